@@ -0,0 +1,103 @@
+//! Checks that the trait implementations of a root type meet the requirements of [`LazyConcat`].
+
+use std::borrow::{Borrow, Cow};
+use std::fmt::Debug;
+use ::concat::{Concat, Prepend};
+use ::error::LazyConcatError;
+use ::length::Length;
+use ::reserve::Reserve;
+use ::sliceable::Sliceable;
+use ::LazyConcat;
+
+/// Check that the implementations of [`Concat`], [`Prepend`], [`Length`], [`Reserve`] and 
+/// [`Sliceable`] for a root type `T`, and its slice type `B`, agree with each other and work inside a 
+/// [`LazyConcat`]. The `root` and `fragments` are concatenated in various ways and the results compared.
+/// 
+/// This is intended to be called from the tests of a crate that implements the traits for its own types, 
+/// most easily with the [`lazy_concat_test_suite!`](::lazy_concat_test_suite!) macro.
+/// 
+/// # Panics
+/// Panics, with a description of the problem, if any requirement is not met.
+/// 
+/// # Examples
+/// 
+/// ```
+/// lazy_concat::check_impls(String::from("ab"), &["c", "", "形聲"]);
+/// lazy_concat::check_impls(vec![1, 2], &[&[3][..], &[4, 5][..]]);
+/// ```
+pub fn check_impls<'a, T, B>(root: T, fragments: &[&'a B])
+where
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve + Sliceable<Slice = B> + Debug,
+    B: ToOwned<Owned = T> + ?Sized + Length + PartialEq + Debug,
+{
+    let root_len = root.len();
+    assert_eq!(root_len, root.borrow().len(), "Length of the root must match the length of its borrowed slice");
+    assert_eq!(root.borrow(), root.get_slice(..), "get_slice(..) must return the whole root");
+    assert_eq!(
+        Err(LazyConcatError::OutOfBounds { end: root_len + 1, len: root_len }), 
+        root.try_get_slice(..root_len + 1),
+        "try_get_slice must fail when the range is out of bounds");
+
+    let mut expected = root.borrow().to_owned();
+    for &fragment in fragments {
+        let len = expected.len();
+        let borrowed = T::default().concat(Cow::Borrowed(fragment));
+        assert_eq!(fragment, borrowed.borrow(), "Concatenating onto an empty value must copy the fragment");
+        let owned = fragment.to_owned();
+        assert_eq!(fragment.len(), owned.len(), "Length of a fragment must match its owned value");
+        let prepended = owned.prepend(expected.borrow().to_owned());
+
+        expected.reserve(fragment.len());
+        assert!(expected.capacity() >= len + fragment.len(), "Reserve must make room for the additional length");
+        expected = expected.concat(Cow::Borrowed(fragment));
+        assert_eq!(len + fragment.len(), expected.len(), "Concatenating must add the lengths");
+        assert_eq!(expected.borrow(), prepended.borrow(), "Prepending must be the same as concatenating in reverse");
+        assert_eq!(fragment, expected.get_slice(len..), "The concatenated data must end with the fragment");
+    }
+
+    let lazy = || fragments.iter()
+        .fold(LazyConcat::new(root.borrow().to_owned()), |lz, &fragment| lz.and_concat(Cow::Borrowed(fragment)));
+    let mut lz = lazy();
+    for end in root_len ..= expected.len() {
+        if let Some(len) = lz.normalize_to_len(end) {
+            assert!(len >= end, "normalize_to_len must normalize at least the requested length");
+            assert_eq!(expected.get_slice(..len), lz.get_slice(..len), "Partially normalized data must match");
+        }
+    }
+    assert_eq!(expected.borrow(), lz.done().borrow(), "Normalizing must concatenate every fragment in order");
+
+    let owned = fragments.iter()
+        .fold(LazyConcat::new(root.borrow().to_owned()), |lz, &fragment| lz.and_concat(Cow::Owned(fragment.to_owned())));
+    assert_eq!(expected.borrow(), owned.done().borrow(), "Normalizing owned fragments must give the same result");
+    let mut lz = lazy();
+    lz.try_normalize().expect("Normalizing must not fail");
+    assert_eq!(expected.borrow(), lz.get_slice(..), "Fallible normalization must give the same result");
+}
+
+/// Generate a `#[test]` which runs [`check_impls`](::check_impls) on a root and a list of fragments.
+/// 
+/// # Examples
+/// 
+/// ```
+/// #[macro_use]
+/// extern crate lazy_concat;
+/// 
+/// lazy_concat_test_suite!(string_impls, String::from("ab"), ["c", "", "de"]);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! lazy_concat_test_suite {
+    ($name:ident, $root:expr, [$($fragment:expr),* $(,)*]) => {
+        #[test]
+        fn $name() {
+            $crate::check_impls($root, &[$($fragment),*]);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    lazy_concat_test_suite!(string_impls, String::from("ab"), ["c", "", "形聲"]);
+    lazy_concat_test_suite!(empty_string_impls, String::new(), []);
+    lazy_concat_test_suite!(vec_impls, vec![1u8, 2], [&[3][..], &[][..], &[4, 5, 6][..]]);
+}
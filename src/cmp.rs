@@ -0,0 +1,269 @@
+//! Comparison and hashing of the logical contents of a [`LazyConcat`], across fragment boundaries and
+//! without normalizing.
+
+use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use ::concat::{Concat, Prepend};
+use ::length::Length;
+use ::reserve::Reserve;
+use ::LazyConcat;
+
+impl<'a, 'b, const N: usize, const M: usize> PartialEq<LazyConcat<'b, String, str, M>> for LazyConcat<'a, String, str, N> {
+    fn eq(&self, other: &LazyConcat<'b, String, str, M>) -> bool {
+        self.total_len() == other.total_len() && self.bytes().eq(other.bytes())
+    }
+}
+
+impl<'a, const N: usize> Eq for LazyConcat<'a, String, str, N> {}
+
+impl<'a, const N: usize> PartialEq<str> for LazyConcat<'a, String, str, N> {
+    fn eq(&self, other: &str) -> bool {
+        self.total_len() == other.len() && self.bytes().eq(other.bytes())
+    }
+}
+
+impl<'a, 'b, const N: usize> PartialEq<&'b str> for LazyConcat<'a, String, str, N> {
+    fn eq(&self, other: &&'b str) -> bool {
+        *self == **other
+    }
+}
+
+impl<'a, const N: usize> PartialEq<String> for LazyConcat<'a, String, str, N> {
+    fn eq(&self, other: &String) -> bool {
+        *self == **other
+    }
+}
+
+impl<'a, const N: usize> PartialEq<LazyConcat<'a, String, str, N>> for str {
+    fn eq(&self, other: &LazyConcat<'a, String, str, N>) -> bool {
+        *other == *self
+    }
+}
+
+impl<'a, const N: usize> PartialEq<LazyConcat<'a, String, str, N>> for &str {
+    fn eq(&self, other: &LazyConcat<'a, String, str, N>) -> bool {
+        *other == **self
+    }
+}
+
+impl<'a, const N: usize> PartialEq<LazyConcat<'a, String, str, N>> for String {
+    fn eq(&self, other: &LazyConcat<'a, String, str, N>) -> bool {
+        *other == **self
+    }
+}
+
+/// Compares the bytes lexicographically, in the same order as `str`.
+impl<'a, 'b, const N: usize, const M: usize> PartialOrd<LazyConcat<'b, String, str, M>> for LazyConcat<'a, String, str, N> {
+    fn partial_cmp(&self, other: &LazyConcat<'b, String, str, M>) -> Option<Ordering> {
+        Some(self.bytes().cmp(other.bytes()))
+    }
+}
+
+impl<'a, const N: usize> Ord for LazyConcat<'a, String, str, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes().cmp(other.bytes())
+    }
+}
+
+/// Produces the same hash as the equivalent `String`, with any `Hasher`. 
+/// 
+/// A `Hasher` may give a different result when the same data is divided differently between calls to 
+/// `write`, so if the data is spread over more than one non-empty fragment then it is first copied into a 
+/// temporary `String`, to be hashed in one piece.
+impl<'a, const N: usize> Hash for LazyConcat<'a, String, str, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.contiguous() {
+            Some(data) => data.hash(state),
+            None => self.fragments_iter().collect::<String>().hash(state),
+        }
+    }
+}
+
+impl<'a, 'b, I, J, const N: usize, const M: usize> PartialEq<LazyConcat<'b, Vec<J>, [J], M>> for LazyConcat<'a, Vec<I>, [I], N>
+where
+    I: Clone + PartialEq<J>,
+    J: Clone,
+{
+    fn eq(&self, other: &LazyConcat<'b, Vec<J>, [J], M>) -> bool {
+        self.total_len() == other.total_len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<'a, I: Clone + Eq, const N: usize> Eq for LazyConcat<'a, Vec<I>, [I], N> {}
+
+impl<'a, I, J, const N: usize> PartialEq<[J]> for LazyConcat<'a, Vec<I>, [I], N>
+where
+    I: Clone + PartialEq<J>,
+{
+    fn eq(&self, other: &[J]) -> bool {
+        self.total_len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<'a, 'b, I, J, const N: usize> PartialEq<&'b [J]> for LazyConcat<'a, Vec<I>, [I], N>
+where
+    I: Clone + PartialEq<J>,
+{
+    fn eq(&self, other: &&'b [J]) -> bool {
+        *self == **other
+    }
+}
+
+impl<'a, I, J, const N: usize> PartialEq<Vec<J>> for LazyConcat<'a, Vec<I>, [I], N>
+where
+    I: Clone + PartialEq<J>,
+{
+    fn eq(&self, other: &Vec<J>) -> bool {
+        *self == **other
+    }
+}
+
+impl<'a, I, J, const N: usize> PartialEq<LazyConcat<'a, Vec<I>, [I], N>> for Vec<J>
+where
+    I: Clone + PartialEq<J>,
+{
+    fn eq(&self, other: &LazyConcat<'a, Vec<I>, [I], N>) -> bool {
+        *other == **self
+    }
+}
+
+impl<'a, I, J, const N: usize> PartialEq<LazyConcat<'a, Vec<I>, [I], N>> for [J]
+where
+    I: Clone + PartialEq<J>,
+{
+    fn eq(&self, other: &LazyConcat<'a, Vec<I>, [I], N>) -> bool {
+        *other == *self
+    }
+}
+
+impl<'a, I, J, const N: usize> PartialEq<LazyConcat<'a, Vec<I>, [I], N>> for &[J]
+where
+    I: Clone + PartialEq<J>,
+{
+    fn eq(&self, other: &LazyConcat<'a, Vec<I>, [I], N>) -> bool {
+        *other == **self
+    }
+}
+
+/// Compares the items lexicographically, in the same order as a slice.
+impl<'a, 'b, I, const N: usize, const M: usize> PartialOrd<LazyConcat<'b, Vec<I>, [I], M>> for LazyConcat<'a, Vec<I>, [I], N>
+where
+    I: Clone + PartialOrd,
+{
+    fn partial_cmp(&self, other: &LazyConcat<'b, Vec<I>, [I], M>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, I: Clone + Ord, const N: usize> Ord for LazyConcat<'a, Vec<I>, [I], N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Produces the same hash as the equivalent `Vec`, with any `Hasher`. 
+/// 
+/// Slices of some types, such as integers, are hashed with a single call to `write`, so if the data is 
+/// spread over more than one non-empty fragment then it is first cloned into a temporary `Vec`, to be 
+/// hashed in one piece.
+impl<'a, I: Clone + Hash, const N: usize> Hash for LazyConcat<'a, Vec<I>, [I], N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.contiguous() {
+            Some(data) => data.hash(state),
+            None => self.iter().cloned().collect::<Vec<I>>().hash(state),
+        }
+    }
+}
+
+impl<'a, T, B, const N: usize> LazyConcat<'a, T, B, N> 
+where
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+    B: ToOwned<Owned = T> + ?Sized + Length,
+{
+    /// All of the data as one slice, if it is not spread over more than one non-empty fragment.
+    fn contiguous(&self) -> Option<&B> {
+        let mut non_empty = self.fragments_iter().filter(|fragment| !fragment.is_empty());
+        match (non_empty.next(), non_empty.next()) {
+            (Some(data), None) => Some(data),
+            (None, _) => Some(self.root.borrow()),
+            (Some(_), Some(_)) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::LazyConcat;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// A `Hasher` that records every call to `write`, so that the result depends on how data is divided 
+    /// between calls, unlike `DefaultHasher`.
+    #[derive(Default)]
+    struct WriteLog(Vec<Vec<u8>>);
+
+    impl Hasher for WriteLog {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.push(bytes.to_vec());
+        }
+    }
+
+    fn writes_of<T: Hash + ?Sized>(value: &T) -> Vec<Vec<u8>> {
+        let mut hasher = WriteLog::default();
+        value.hash(&mut hasher);
+        hasher.0
+    }
+
+    #[test]
+    fn string_eq_across_fragments() {
+        let a = LazyConcat::new(String::from("ab")).and_concat("cd").and_concat("e");
+        let b = LazyConcat::new(String::new()).and_concat("a").and_concat(String::from("bcde"));
+        assert_eq!(a, b);
+        assert_eq!(a, "abcde");
+        assert_eq!(String::from("abcde"), a);
+        assert!(a != "abcd");
+        assert!(a < LazyConcat::new(String::from("abd")));
+        assert!(a > LazyConcat::new(String::from("abcd")));
+    }
+
+    #[test]
+    fn string_hash_matches() {
+        let lz = LazyConcat::new(String::from("ab")).and_concat("c").and_concat("");
+        assert_eq!(hash_of("abc"), hash_of(&lz));
+        assert_eq!(hash_of(&String::from("abc")), hash_of(&lz));
+        assert_eq!(writes_of("abc"), writes_of(&lz));
+        let other = LazyConcat::new(String::new()).and_concat("").and_concat("a").and_concat("bc");
+        assert_eq!(writes_of(&other), writes_of(&lz));
+        let single = LazyConcat::new(String::new()).and_concat("abc");
+        assert_eq!(writes_of("abc"), writes_of(&single));
+    }
+
+    #[test]
+    fn vec_eq_ord_and_hash() {
+        let a = [2, 3];
+        let lz = LazyConcat::new(vec![1]).and_concat(&a[..]).and_concat(vec![4]);
+        assert_eq!(lz, vec![1, 2, 3, 4]);
+        assert_eq!(lz, &[1, 2, 3, 4][..]);
+        assert_eq!(&[1, 2, 3, 4][..], lz);
+        assert!([1, 2, 3, 4][..] == lz);
+        assert!(lz != vec![1, 2, 3]);
+        assert!(lz < LazyConcat::new(vec![1, 2, 4]));
+        assert_eq!(hash_of(&vec![1, 2, 3, 4]), hash_of(&lz));
+        assert_eq!(hash_of(&[1, 2, 3, 4][..]), hash_of(&lz));
+        let bytes = LazyConcat::new(vec![1u8]).and_concat(&[2, 3][..]);
+        let other = LazyConcat::new(vec![1u8, 2]).and_concat(&[3][..]);
+        assert_eq!(writes_of(&vec![1u8, 2, 3]), writes_of(&bytes));
+        assert_eq!(writes_of(&other), writes_of(&bytes));
+    }
+}
@@ -7,7 +7,11 @@ use std::cell::Ref;
 /// Implementations are provided for `Vec<T>` and `String`, and other common owned types,
 /// with a variety of compatible types that can be concatenated.
 /// 
+/// A root type `T` with slice type `B` must implement `Concat<Cow<'a, B>>` to be used in a 
+/// [`LazyConcat`](::LazyConcat). The result of `a.concat(b)` must be the data of `a` followed by the data
+/// of `b`, with a [`Length`](::Length) of `a.len() + b.len()`.
 /// 
+/// Use [`check_impls`](::check_impls) to test that an implementation meets these requirements.
 pub trait Concat<T = Self>
 where
     T: ?Sized,
@@ -17,7 +21,7 @@ where
 
 impl<'a> Concat<&'a str> for String {
     fn concat(mut self, other: &'a str) -> Self {
-        self.push_str(&other);
+        self.push_str(other);
         self
     }
 }
@@ -67,7 +71,7 @@ where
     }
 }
 
-impl<'a, C> Concat<C> for OsString 
+impl<C> Concat<C> for OsString 
 where
     C: AsRef<OsStr>,
 {
@@ -122,6 +126,47 @@ where
     }
 }
 
+/// Concatenation of another value onto the front of an owned value.
+///
+/// This lets [`LazyConcat`](::LazyConcat) adopt the allocation of a large owned fragment as its root,
+/// only copying the (smaller) existing root into it, rather than copying the whole fragment.
+/// 
+/// The result of `a.prepend(b)` must be the same as `b.concat(a)`, but it should reuse the buffer of `a`.
+pub trait Prepend<T = Self> {
+    /// Whether `prepend` reuses the buffer of `self`. If it can't, [`LazyConcat`](::LazyConcat) only adopts 
+    /// a fragment's buffer when the root is empty, since the fragment would be copied anyway.
+    const IN_PLACE: bool = true;
+
+    fn prepend(self, other: T) -> Self;
+}
+
+impl Prepend for String {
+    fn prepend(mut self, other: String) -> Self {
+        self.insert_str(0, &other);
+        self
+    }
+}
+
+impl<T> Prepend for Vec<T> {
+    fn prepend(mut self, other: Vec<T>) -> Self {
+        self.splice(0..0, other);
+        self
+    }
+}
+
+impl Prepend for OsString {
+    // `OsString` can't insert at the front, so this copies both values into a new buffer
+    const IN_PLACE: bool = false;
+
+    fn prepend(self, other: OsString) -> Self {
+        // Keep any room that was reserved in `self`, as the result replaces it
+        let mut result = OsString::with_capacity(other.len() + self.capacity());
+        result.push(other);
+        result.push(self);
+        result
+    }
+}
+
 macro_rules! vec_concat_array {
     ($($n: expr),*) => {
         $(
@@ -238,7 +283,7 @@ mod tests {
     #[test]
     fn vec_concat_slice() {
         let s = vec![1, 2, 3];
-        let to_append = vec![4, 5];
+        let to_append = [4, 5];
         let res: Vec<u32> = s.concat(&to_append[..]);
         assert_eq!(res, vec![1, 2, 3, 4, 5]);
     }    
@@ -268,11 +313,41 @@ mod tests {
      #[test]
     fn cow_vec_concat_slice() {
         let s = Cow::from(vec![1, 2, 3]);
-        let to_append = vec![4, 5];
+        let to_append = [4, 5];
         let res: Cow<[u32]> = s.concat(&to_append[..]);
         assert_eq!(res, vec![1, 2, 3, 4, 5]);
     }
     
+    #[test]
+    fn string_prepend_string() {
+        let s = String::from("123");
+        let res: String = s.prepend(String::from("abc"));
+        assert_eq!(res, "abc123");
+    }
+
+    #[test]
+    fn vec_prepend_vec() {
+        let s = vec![4, 5];
+        let res: Vec<u32> = s.prepend(vec![1, 2, 3]);
+        assert_eq!(res, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn osstring_prepend_osstring() {
+        let s = OsString::from("123");
+        let res: OsString = s.prepend(OsString::from("abc"));
+        assert_eq!(res, OsString::from("abc123"));
+    }
+
+    #[test]
+    fn osstring_prepend_keeps_capacity() {
+        let mut s = OsString::from("123");
+        s.reserve(100);
+        let capacity = s.capacity();
+        let res: OsString = s.prepend(OsString::from("abc"));
+        assert!(res.capacity() >= capacity + 3);
+    }
+
     #[test]
     fn vec_concat_array_1() {
         let s = vec![1, 2, 3];
@@ -0,0 +1,33 @@
+use std::{slice, str};
+
+/// Iteration over the individual elements of a slice type, such as the items of `[T]` or the `char`s of 
+/// `str`.
+///
+/// Implementing this for a custom slice type gives [`LazyConcat::elements`](::LazyConcat::elements), which
+/// iterates over the elements of the root and every fragment without normalizing.
+pub trait Elements {
+    /// The type of each element.
+    type Item<'b> where Self: 'b;
+    /// The iterator over the elements of a single slice.
+    type Iter<'b>: Iterator<Item = Self::Item<'b>> where Self: 'b;
+
+    fn elements(&self) -> Self::Iter<'_>;
+}
+
+impl<T> Elements for [T] {
+    type Item<'b> = &'b T where T: 'b;
+    type Iter<'b> = slice::Iter<'b, T> where T: 'b;
+
+    fn elements(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl Elements for str {
+    type Item<'b> = char;
+    type Iter<'b> = str::Chars<'b>;
+
+    fn elements(&self) -> Self::Iter<'_> {
+        self.chars()
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::TryReserveError;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Errors from the fallible (`try_`) methods of [`LazyConcat`](::LazyConcat) and
+/// [`Sliceable`](::Sliceable).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LazyConcatError {
+    /// The range extends beyond the end of the data, which has length `len`. If only the start of the 
+    /// range is out of bounds, `end` is the start.
+    OutOfBounds { end: usize, len: usize },
+    /// The range starts after it ends.
+    InvertedRange { start: usize, end: usize },
+    /// The range extends beyond the normalized data, which has length `normalized_len`, but would be 
+    /// available after further normalization.
+    NotNormalized { end: usize, normalized_len: usize },
+    /// The range starts or ends at `index`, which is not on a `char` boundary.
+    NotCharBoundary { index: usize },
+    /// Concatenating would make the total length `len`, which is more than the limit of `max`.
+    LengthLimitExceeded { len: usize, max: usize },
+    /// Concatenating would make the number of fragments more than the limit of `max`.
+    FragmentLimitExceeded { max: usize },
+    /// Memory could not be allocated for normalization.
+    AllocationFailure(TryReserveError),
+}
+
+impl Display for LazyConcatError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            LazyConcatError::OutOfBounds { end, len } => 
+                write!(f, "range end {} is out of bounds for length {}", end, len),
+            LazyConcatError::InvertedRange { start, end } => 
+                write!(f, "range starts at {} but ends at {}", start, end),
+            LazyConcatError::NotNormalized { end, normalized_len } => 
+                write!(f, "range end {} is beyond the normalized length {}", end, normalized_len),
+            LazyConcatError::NotCharBoundary { index } => 
+                write!(f, "index {} is not on a char boundary", index),
+            LazyConcatError::LengthLimitExceeded { len, max } => 
+                write!(f, "length {} exceeds the limit of {}", len, max),
+            LazyConcatError::FragmentLimitExceeded { max } => 
+                write!(f, "number of fragments exceeds the limit of {}", max),
+            LazyConcatError::AllocationFailure(e) => 
+                write!(f, "allocation failed during normalization: {}", e),
+        }
+    }
+}
+
+impl Error for LazyConcatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LazyConcatError::AllocationFailure(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<TryReserveError> for LazyConcatError {
+    fn from(e: TryReserveError) -> Self {
+        LazyConcatError::AllocationFailure(e)
+    }
+}
@@ -0,0 +1,759 @@
+use std::{
+    borrow::{Cow, Borrow},
+    fmt::{self, Debug, Formatter},
+    mem,
+    sync::Arc,
+};
+use ::concat::{Concat, Prepend};
+use ::error::LazyConcatError;
+use ::length::Length;
+use ::inline_vec::InlineVec;
+use ::reserve::Reserve;
+use ::sliceable::Sliceable;
+use ::split_off::SplitOff;
+use ::LazyConcat;
+
+pub(crate) enum Fragment<'a, B>
+where
+    B: ?Sized + 'a + ToOwned
+{
+    Value(Cow<'a, B>),
+    /// An owned value which is shared with forks of the same [`LazyConcat`](::LazyConcat). It is only 
+    /// copied if it needs to be mutated while it is still shared.
+    Shared(Arc<B::Owned>),
+}
+
+impl<'a, B: 'a> Fragment<'a, B>
+where
+    B: ToOwned + ?Sized,
+{
+    #[inline]
+    pub(crate) fn get(self) -> Cow<'a, B> {
+        match self {
+            Fragment::Value(b) => b,
+            Fragment::Shared(shared) => Cow::Owned(unwrap_or_clone::<B>(shared)),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn borrow(&self) -> &B {
+        match self {
+            Fragment::Value(ref b) => b.borrow(),
+            Fragment::Shared(ref shared) => (**shared).borrow(),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize
+    where
+        B: Length,
+    {
+        self.borrow().len()
+    }
+
+    /// The number of bytes of this fragment's own data, which is `0` if it is borrowed.
+    pub(crate) fn owned_byte_len(&self) -> usize
+    where
+        B: Length,
+    {
+        if self.is_borrowed() { 0 } else { self.borrow().byte_len() }
+    }
+
+    #[inline]
+    pub(crate) fn to_mut(&mut self) -> &mut B::Owned {
+        if let Fragment::Shared(ref shared) = self {
+            if Arc::strong_count(shared) > 1 {
+                *self = Fragment::Value(Cow::Owned((**shared).borrow().to_owned()));
+            }
+        }
+        match self {
+            Fragment::Value(ref mut value) => value.to_mut(),
+            Fragment::Shared(ref mut shared) => Arc::get_mut(shared)
+                .expect("A shared fragment is copied above unless it is unique"),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn owned_mut(&mut self) -> Option<&mut B::Owned> {
+        match self {
+            Fragment::Value(Cow::Owned(ref mut owned)) => Some(owned),
+            Fragment::Value(Cow::Borrowed(_)) => None,
+            Fragment::Shared(ref mut shared) => Arc::get_mut(shared),
+        }
+    }
+
+    /// The owned value of this fragment, if it has one that is not shared with any other fragment.
+    #[inline]
+    pub(crate) fn owned(&self) -> Option<&B::Owned> {
+        match self {
+            Fragment::Value(Cow::Owned(ref owned)) => Some(owned),
+            Fragment::Value(Cow::Borrowed(_)) => None,
+            Fragment::Shared(ref shared) if Arc::strong_count(shared) == 1 => Some(shared),
+            Fragment::Shared(_) => None,
+        }
+    }
+
+    /// Convert an owned fragment into one that can be shared between forks.
+    fn into_shared(self) -> Self {
+        match self {
+            Fragment::Value(Cow::Owned(owned)) => Fragment::Shared(Arc::new(owned)),
+            fragment => fragment,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_borrowed(&self) -> bool {
+        matches!(self, Fragment::Value(Cow::Borrowed(_)))
+    }
+
+    #[inline]
+    pub(crate) fn is_owned(&self) -> bool {
+        self.owned().is_some()
+    }
+
+    /// Whether a fragment of length `len` should be merged into this one, when coalescing fragments that
+    /// are shorter than `below`.
+    #[inline]
+    fn absorbs(&self, len: usize, below: usize) -> bool
+    where
+        B: Length,
+    {
+        len < below && (self.is_owned() || self.len() < below)
+    }
+
+    /// Concatenate another fragment onto the end of this one, first converting this one to an owned value
+    /// if it is borrowed.
+    fn coalesce(&mut self, other: Cow<'a, B>)
+    where
+        B::Owned: Concat<Cow<'a, B>> + Default,
+    {
+        let owned = mem::take(self.to_mut());
+        *self = Fragment::Value(Cow::Owned(owned.concat(other)));
+    }
+}
+
+impl<'a, B: 'a> Fragment<'a, B>
+where
+    B: ToOwned + ?Sized + Sliceable<Slice = B>,
+    B::Owned: SplitOff,
+{
+    /// Split the fragment at `at`, returning the part from `at` onwards. A borrowed fragment is split 
+    /// into two borrowed fragments, without copying.
+    fn split_off(&mut self, at: usize) -> Self {
+        match self {
+            Fragment::Value(Cow::Borrowed(value)) => {
+                let value: &'a B = value;
+                *self = Fragment::Value(Cow::Borrowed(value.get_slice(..at)));
+                Fragment::Value(Cow::Borrowed(value.get_slice(at..)))
+            }
+            _ => Fragment::Value(Cow::Owned(self.to_mut().split_off(at))),
+        }
+    }
+}
+
+/// Take the value out of `shared` if there are no other references to it, otherwise copy it.
+fn unwrap_or_clone<B>(shared: Arc<B::Owned>) -> B::Owned
+where
+    B: ToOwned + ?Sized,
+{
+    Arc::try_unwrap(shared).unwrap_or_else(|shared| (*shared).borrow().to_owned())
+}
+
+impl<'a, B> Clone for Fragment<'a, B>
+where
+    B: ToOwned + ?Sized + 'a,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Fragment::Value(ref value) => Fragment::Value(value.clone()),
+            Fragment::Shared(ref shared) => Fragment::Shared(shared.clone()),
+        }
+    }
+}
+
+impl<'a, B> Debug for Fragment<'a, B>
+where
+    B: ToOwned + 'a,
+    B: Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        self.borrow().fmt(f)
+    }
+}
+
+/// A list of owned or borrowed fragments of data, which are waiting to be concatenated.
+///
+/// Every [`LazyConcat`](::LazyConcat) keeps its fragments in a `Fragments`, which can be borrowed
+/// separately from the normalized root with [`split_root`](::LazyConcat::split_root).
+///
+/// Up to `N` fragments are stored inline, without any heap allocation for the list.
+pub struct Fragments<'a, B, const N: usize = 4>
+where
+    B: ?Sized + 'a + ToOwned
+{
+    pub(crate) list: InlineVec<Fragment<'a, B>, N>,
+    /// The total length of the fragments
+    pub(crate) len: usize,
+    /// The total [`byte_len`](Length::byte_len) of the fragments that are not borrowed
+    pub(crate) owned_bytes: usize,
+    pub(crate) coalesce_below: usize,
+}
+
+impl<'a, B, const N: usize> Fragments<'a, B, N>
+where
+    B: ToOwned + ?Sized + Length,
+    B::Owned: Concat<Cow<'a, B>> + Default,
+{
+    /// Construct a new, empty, list of fragments.
+    pub fn new() -> Self {
+        Fragments { list: InlineVec::new(), len: 0, owned_bytes: 0, coalesce_below: 0 }
+    }
+
+    /// Construct a new, empty, list of fragments, with space for at least `n` fragments before
+    /// reallocating. Nothing is allocated if `n` fragments fit in the inline storage.
+    pub fn with_capacity(n: usize) -> Self {
+        Fragments { list: InlineVec::with_capacity(n), ..Fragments::new() }
+    }
+
+    /// Set a coalescing policy, so that any fragment shorter than `len` is merged into an owned tail fragment
+    /// when it is concatenated, instead of being added to the list. The default is `0`, which disables
+    /// coalescing.
+    pub fn coalesce_below(mut self, len: usize) -> Self {
+        self.coalesce_below = len;
+        self
+    }
+
+    /// Add an owned or borrowed fragment to the end of the list. A short fragment may be merged into the
+    /// previous one, according to the policy set with [`coalesce_below`](Fragments::coalesce_below).
+    pub fn concat<F: Into<Cow<'a, B>>>(&mut self, fragment: F) {
+        self.push(Fragment::Value(fragment.into()));
+    }
+
+    /// Add a fragment to the end of the list, merging it into the previous one if the coalescing policy 
+    /// allows. Otherwise the fragment is moved as it is, so a shared fragment stays shared.
+    fn push(&mut self, fragment: Fragment<'a, B>) {
+        let len = fragment.len();
+        self.len += len;
+        match self.list.last_mut() {
+            Some(tail) if tail.absorbs(len, self.coalesce_below) => {
+                self.owned_bytes -= tail.owned_byte_len();
+                tail.coalesce(fragment.get());
+                self.owned_bytes += tail.owned_byte_len();
+            }
+            _ => {
+                self.owned_bytes += fragment.owned_byte_len();
+                self.list.push(fragment);
+            }
+        }
+    }
+
+    /// Add an owned or borrowed fragment to the end of the list, like [`concat`](Fragments::concat),
+    /// but consume and return `self`, allowing for method chaining.
+    pub fn and_concat<F: Into<Cow<'a, B>>>(mut self, fragment: F) -> Self {
+        self.concat(fragment);
+        self
+    }
+
+    /// Whether a fragment of length `len` would be merged into the last fragment, instead of being added
+    /// to the list, if it was concatenated now.
+    pub(crate) fn absorbs(&self, len: usize) -> bool {
+        self.list.last()
+            .is_some_and(|tail| tail.absorbs(len, self.coalesce_below))
+    }
+
+    /// Merge adjacent fragments that are shorter than the threshold set with
+    /// [`coalesce_below`](Fragments::coalesce_below), following the same rules that are applied when
+    /// concatenating.
+    pub fn compact(&mut self) {
+        let capacity = self.list.len();
+        let list = mem::replace(&mut self.list, InlineVec::with_capacity(capacity));
+        self.len = 0;
+        self.owned_bytes = 0;
+        for fragment in list {
+            self.push(fragment);
+        }
+    }
+
+    /// Split the list in two at a position in the concatenated data, returning the fragments from `at` 
+    /// onwards and leaving the fragments before `at` in place. A fragment that spans `at` is divided; if it
+    /// is borrowed then both parts still borrow the original data. The returned list has the same 
+    /// coalescing policy.
+    /// 
+    /// # Panics
+    /// Panics if `at` is greater than the total length, or if it does not fall on a valid position at 
+    /// which to split a fragment, such as a `char` boundary.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::{Fragments, Length};
+    /// let mut fragments: Fragments<str> = Fragments::new()
+    ///     .and_concat("abc")
+    ///     .and_concat("def");
+    /// let tail = fragments.split_off(2);
+    /// assert_eq!(vec!["ab"], fragments.iter().collect::<Vec<_>>());
+    /// assert_eq!(vec!["c", "def"], tail.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        B: Sliceable<Slice = B>,
+        B::Owned: SplitOff,
+    {
+        assert!(at <= self.len, "`at` out of bounds");
+        let mut offset = 0;
+        let mut index = self.list.len();
+        for (i, fragment) in self.list.iter().enumerate() {
+            if offset + fragment.len() > at {
+                index = i;
+                break;
+            }
+            offset += fragment.len();
+        }
+        let mut list = self.list.split_off(index);
+        if at > offset {
+            if let Some(spanning) = list.first_mut() {
+                let rest = spanning.split_off(at - offset);
+                self.list.push(mem::replace(spanning, rest));
+            }
+        }
+        let tail_len = self.len - at;
+        self.len = at;
+        self.owned_bytes = self.list.iter().map(Fragment::owned_byte_len).sum();
+        let owned_bytes = list.iter().map(Fragment::owned_byte_len).sum();
+        Fragments { list, len: tail_len, owned_bytes, coalesce_below: self.coalesce_below }
+    }
+
+    /// Remove all of the fragments.
+    pub fn clear(&mut self) {
+        self.list = InlineVec::new();
+        self.len = 0;
+        self.owned_bytes = 0;
+    }
+}
+
+impl<'a, T, B, const N: usize> Fragments<'a, B, N>
+where
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+    B: ToOwned<Owned = T> + ?Sized + Length,
+{
+    /// Move all of the fragments of `other` onto the end of this list, leaving `other` empty. Short 
+    /// fragments are merged according to the coalescing policy of this list.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::Fragments;
+    /// let mut a: Fragments<str> = Fragments::new().and_concat("a");
+    /// let mut b: Fragments<str> = Fragments::new().and_concat("b").and_concat("c");
+    /// a.append(&mut b);
+    /// assert_eq!(3, a.num_fragments());
+    /// assert_eq!(0, b.num_fragments());
+    /// ```
+    pub fn append<const M: usize>(&mut self, other: &mut Fragments<'a, B, M>) {
+        let list = mem::take(&mut other.list);
+        other.len = 0;
+        other.owned_bytes = 0;
+        for fragment in list {
+            self.push(fragment);
+        }
+    }
+
+    /// Concatenate every fragment onto the end of `root`, leaving the list empty. The capacity required
+    /// for all of the fragments is reserved in one go. 
+    /// 
+    /// This can be used to append fragments onto a `String` or `Vec` that is owned elsewhere.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::Fragments;
+    /// let mut fragments: Fragments<str> = Fragments::new()
+    ///     .and_concat(", ")
+    ///     .and_concat(String::from("world"));
+    /// let mut greeting = String::from("Hello");
+    /// fragments.normalize(&mut greeting);
+    /// assert_eq!("Hello, world", greeting);
+    /// ```
+    pub fn normalize(&mut self, root: &mut T) {
+        let count = self.num_fragments();
+        self.normalize_fragments(root, count);
+    }
+
+    /// Concatenate every fragment onto the end of `root`, like [`normalize`](Fragments::normalize), but 
+    /// return [`LazyConcatError::AllocationFailure`] instead of aborting if the memory cannot be allocated.
+    /// In that case, neither `root` nor the fragments are changed. Only the target is allocated fallibly: a
+    /// fragment that is still shared with a [`fork`](Fragments::fork) is copied with an infallible allocation.
+    pub fn try_normalize(&mut self, root: &mut T) -> Result<(), LazyConcatError> {
+        let count = self.num_fragments();
+        self.try_normalize_fragments(root, count)
+    }
+
+    /// Concatenate enough fragments onto the end of `root` that it has a length of at least `len`, and 
+    /// return its new length. If there are not enough fragments to make up the required length then 
+    /// `None` is returned and no work is done.
+    pub fn normalize_to_len(&mut self, root: &mut T, len: usize) -> Option<usize> {
+        let count = self.fragments_needed(root, len)?;
+        if count > 0 {
+            self.normalize_fragments(root, count);
+        }
+        Some(root.len())
+    }
+
+    /// Concatenate enough fragments onto the end of `root` that it has a length of at least `len`, like 
+    /// [`normalize_to_len`](Fragments::normalize_to_len), but return [`LazyConcatError::OutOfBounds`] if 
+    /// there are not enough fragments, or [`LazyConcatError::AllocationFailure`] if the memory cannot be
+    /// allocated. No fragments are normalized in either case.
+    pub fn try_normalize_to_len(&mut self, root: &mut T, len: usize) -> Result<usize, LazyConcatError> {
+        let count = self.fragments_needed(root, len)
+            .ok_or(LazyConcatError::OutOfBounds { end: len, len: root.len() + self.len })?;
+        if count > 0 {
+            self.try_normalize_fragments(root, count)?;
+        }
+        Ok(root.len())
+    }
+
+    /// The number of fragments that must be normalized for `root` to have a length of at least `len`, or
+    /// `None` if there are not enough fragments.
+    fn fragments_needed(&self, root: &T, len: usize) -> Option<usize> {
+        if root.len() >= len {
+            return Some(0);
+        }
+        self.list
+            .iter()
+            .scan(0, |total, fragment| {
+                *total += fragment.len();
+                Some(*total)
+            })
+            .position(|s| s >= len - root.len())
+            .map(|num| num + 1)
+    }
+
+    /// Make a copy of the list which shares the buffers of owned fragments with this one, instead of 
+    /// copying them. Borrowed fragments are copied as references, as with `clone`. A shared buffer is only 
+    /// copied if one of the lists needs to mutate it, or if it is normalized while still shared.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::Fragments;
+    /// let mut header: Fragments<str> = Fragments::new()
+    ///     .and_concat(String::from("Header: "));
+    /// let body = header.fork().and_concat("body");
+    /// header.concat("other body");
+    /// assert_eq!(vec!["Header: ", "body"], body.iter().collect::<Vec<_>>());
+    /// assert_eq!(vec!["Header: ", "other body"], header.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn fork(&mut self) -> Self {
+        let list = mem::take(&mut self.list);
+        for fragment in list {
+            self.list.push(fragment.into_shared());
+        }
+        self.clone()
+    }
+
+    /// Insert `root` as an owned fragment in front of all of the others.
+    pub(crate) fn push_front(&mut self, root: T) {
+        let capacity = self.list.len() + 1;
+        let list = mem::replace(&mut self.list, InlineVec::with_capacity(capacity));
+        self.len += root.len();
+        self.owned_bytes += root.byte_len();
+        self.list.push(Fragment::Value(Cow::Owned(root)));
+        for fragment in list {
+            self.list.push(fragment);
+        }
+    }
+
+    /// Turn the list into a [`LazyConcat`], with `root` as the normalized data in front of the fragments.
+    /// The coalescing policy of the list is kept.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::Fragments;
+    /// let fragments: Fragments<[u8]> = Fragments::new()
+    ///     .and_concat(&[3, 4][..]);
+    /// let lz = fragments.to_lazy_concat(vec![1, 2]);
+    /// assert_eq!(vec![1, 2, 3, 4], lz.done());
+    /// ```
+    pub fn to_lazy_concat(self, root: T) -> LazyConcat<'a, T, B, N> {
+        let mut lz = LazyConcat::new_inline(root);
+        let (_, fragments) = lz.split_root();
+        *fragments = self;
+        lz
+    }
+
+    /// Concatenate the first `count` fragments onto `root`, reserving the capacity required for them
+    /// first. When every fragment is normalized the exact capacity is reserved, since nothing more is 
+    /// expected. Otherwise the usual amortized growth is kept, as more fragments are still to come.
+    /// 
+    /// If the first fragment is owned and it is cheaper to concatenate onto its buffer than onto the root,
+    /// then that buffer is adopted as the new root, with the old root moved in front of it.
+    pub(crate) fn normalize_fragments(&mut self, root: &mut T, count: usize) {
+        let len = self.len_after_normalizing(root, count);
+        let adopt = self.should_adopt_first(root, count);
+        let all = count == self.list.len();
+        let target = self.normalization_target(root, adopt);
+        let additional = len - target.len();
+        if all {
+            target.reserve_exact(additional);
+        } else {
+            target.reserve(additional);
+        }
+        self.concat_fragments(root, count, adopt);
+    }
+
+    /// Concatenate the first `count` fragments onto `root`, like `normalize_fragments`, but reserve the
+    /// capacity fallibly, before anything is moved. Only a fragment whose buffer could be adopted without 
+    /// moving the old root in front of it is adopted, so that no other allocation is needed.
+    pub(crate) fn try_normalize_fragments(&mut self, root: &mut T, count: usize) -> Result<(), LazyConcatError> {
+        let len = self.len_after_normalizing(root, count);
+        let adopt = root.is_empty() && self.should_adopt_first(root, count);
+        let all = count == self.list.len();
+        let target = self.normalization_target(root, adopt);
+        let additional = len - target.len();
+        if all {
+            target.try_reserve_exact(additional)?;
+        } else {
+            target.try_reserve(additional)?;
+        }
+        self.concat_fragments(root, count, adopt);
+        Ok(())
+    }
+
+    fn len_after_normalizing(&self, root: &T, count: usize) -> usize {
+        root.len() + self.list.iter().take(count).map(Fragment::len).sum::<usize>()
+    }
+
+    fn should_adopt_first(&self, root: &T, count: usize) -> bool {
+        match self.list.iter().take(count).next().and_then(Fragment::owned) {
+            Some(owned) => should_adopt(root, owned),
+            None => false,
+        }
+    }
+
+    /// The buffer that fragments will be concatenated onto. If `adopt` is `true`, the first fragment must 
+    /// be owned.
+    fn normalization_target<'b>(&'b mut self, root: &'b mut T, adopt: bool) -> &'b mut T {
+        if adopt {
+            self.list.first_mut()
+                .and_then(Fragment::owned_mut)
+                .expect("Only an owned fragment can be adopted")
+        } else {
+            root
+        }
+    }
+
+    fn concat_fragments(&mut self, root: &mut T, count: usize, adopt: bool) {
+        let len = self.list.iter().take(count).map(Fragment::len).sum::<usize>();
+        let owned_bytes = self.list.iter().take(count).map(Fragment::owned_byte_len).sum::<usize>();
+        self.len -= len;
+        self.owned_bytes -= owned_bytes;
+        let mut normalized = mem::take(root);
+        let mut fragments = self.list.split_to(count)
+            .into_iter()
+            .map(Fragment::get);
+        if adopt {
+            if let Some(Cow::Owned(owned)) = fragments.next() {
+                normalized = if normalized.is_empty() { owned } else { owned.prepend(normalized) };
+            }
+        }
+        *root = fragments.fold(normalized, Concat::concat);
+    }
+}
+
+/// Whether an owned fragment's buffer should become the root, instead of being copied into it. This is 
+/// the case when the fragment holds at least as much data as the root, and has more room to grow. A 
+/// non-empty root must be prepended in place, or else the fragment is copied either way.
+fn should_adopt<T: Length + Reserve + Prepend>(root: &T, owned: &T) -> bool {
+    (root.is_empty() || T::IN_PLACE) && root.len() <= owned.len() && root.capacity() < owned.capacity()
+}
+
+impl<'a, B, const N: usize> Fragments<'a, B, N>
+where
+    B: ToOwned + ?Sized,
+{
+    /// The number of fragments in the list. This is not the same as its [`Length`].
+    #[inline]
+    pub fn num_fragments(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Creates an iterator over borrows of each fragment.
+    pub fn iter(&self) -> impl Iterator<Item = &B> {
+        self.list.iter().map(Fragment::borrow)
+    }
+}
+
+impl<'a, B, const N: usize> Length for Fragments<'a, B, N>
+where
+    B: ToOwned + ?Sized + Length,
+{
+    /// The total length of all of the fragments.
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The total size of all of the fragments in bytes.
+    fn byte_len(&self) -> usize {
+        self.list.iter().map(|fragment| fragment.borrow().byte_len()).sum()
+    }
+}
+
+impl<'a, B, const N: usize> Default for Fragments<'a, B, N>
+where
+    B: ToOwned + ?Sized + Length,
+    B::Owned: Concat<Cow<'a, B>> + Default,
+{
+    fn default() -> Self {
+        Fragments::new()
+    }
+}
+
+impl<'a, B, const N: usize> Clone for Fragments<'a, B, N>
+where
+    B: ToOwned + ?Sized,
+{
+    /// Clone the list. Borrowed fragments are copied as references and owned fragments are cloned.
+    fn clone(&self) -> Self {
+        Fragments {
+            list: self.list.clone(),
+            len: self.len,
+            owned_bytes: self.owned_bytes,
+            coalesce_below: self.coalesce_below,
+        }
+    }
+}
+
+impl<'a, B, const N: usize> Debug for Fragments<'a, B, N>
+where
+    B: ToOwned + ?Sized + Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.list.iter())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fragments;
+    use length::Length;
+
+    #[test]
+    fn concat() {
+        let fragments: Fragments<str> = Fragments::new()
+            .and_concat("abc")
+            .and_concat(String::from("de"));
+        assert_eq!(2, fragments.num_fragments());
+        assert_eq!(5, fragments.len());
+        assert_eq!("[\"abc\", \"de\"]", format!("{:?}", fragments));
+    }
+
+    #[test]
+    fn normalize_to_len() {
+        let mut fragments: Fragments<str> = Fragments::new()
+            .and_concat("ab")
+            .and_concat("cd")
+            .and_concat("ef");
+        let mut root = String::from("x");
+        assert_eq!(None, fragments.normalize_to_len(&mut root, 8));
+        assert_eq!(Some(5), fragments.normalize_to_len(&mut root, 4));
+        assert_eq!("xabcd", root);
+        assert_eq!(1, fragments.num_fragments());
+        assert_eq!(2, fragments.len());
+    }
+
+    #[test]
+    fn append_coalesces() {
+        let mut a: Fragments<str> = Fragments::new()
+            .coalesce_below(3)
+            .and_concat("a");
+        let mut b: Fragments<str, 2> = Fragments::new()
+            .and_concat("b")
+            .and_concat("long");
+        a.append(&mut b);
+        assert_eq!("[\"ab\", \"long\"]", format!("{:?}", a));
+        assert_eq!(6, a.len());
+        assert_eq!(0, b.len());
+    }
+
+    #[test]
+    fn fork_shares_owned_fragments() {
+        let mut a: Fragments<[u8]> = Fragments::new()
+            .and_concat(vec![1, 2])
+            .and_concat(&[3][..]);
+        let mut b = a.fork();
+        assert!(a.list.iter().all(|fragment| !fragment.is_owned()));
+        let mut root = Vec::new();
+        b.normalize(&mut root);
+        assert_eq!(vec![1, 2, 3], root);
+        // The other fork is now the only owner, so it can adopt the buffer
+        let ptr = a.iter().next().unwrap().as_ptr();
+        let mut root = Vec::new();
+        a.normalize(&mut root);
+        assert_eq!(ptr, root.as_ptr());
+    }
+
+    #[test]
+    fn compact_and_append_keep_sharing() {
+        let mut a: Fragments<str> = Fragments::new()
+            .coalesce_below(2)
+            .and_concat(String::from("shared"))
+            .and_concat("x");
+        let ptr = a.iter().next().unwrap().as_ptr();
+        let mut b = a.fork();
+        a.compact();
+        assert_eq!(ptr, a.iter().next().unwrap().as_ptr());
+        let mut c: Fragments<str> = Fragments::new();
+        c.append(&mut b);
+        assert_eq!(ptr, c.iter().next().unwrap().as_ptr());
+        assert_eq!(7, c.len());
+    }
+
+    #[test]
+    fn counts_owned_bytes() {
+        fn recount(fragments: &Fragments<str>) -> usize {
+            fragments.list.iter().filter(|f| !f.is_borrowed()).map(|f| f.len()).sum()
+        }
+        let mut a: Fragments<str> = Fragments::new()
+            .coalesce_below(3)
+            .and_concat("abc")
+            .and_concat("d")
+            .and_concat(String::from("efgh"))
+            .and_concat("ij");
+        assert_eq!(6, a.owned_bytes);
+        let mut b = a.split_off(2);
+        assert_eq!((0, 6), (a.owned_bytes, b.owned_bytes));
+        a.append(&mut b);
+        // "ab", "c" and "d" are coalesced into one owned fragment
+        assert_eq!((10, 0), (a.owned_bytes, b.owned_bytes));
+        let mut root = String::new();
+        a.normalize_to_len(&mut root, 4);
+        assert_eq!(recount(&a), a.owned_bytes);
+        a.clear();
+        assert_eq!(0, a.owned_bytes);
+    }
+
+    #[test]
+    fn byte_len_of_wide_elements() {
+        let a = [1u64, 2];
+        let fragments: Fragments<[u64]> = Fragments::new()
+            .and_concat(&a[..])
+            .and_concat(vec![3]);
+        assert_eq!(3, fragments.len());
+        assert_eq!(24, fragments.byte_len());
+    }
+
+    #[test]
+    fn compact() {
+        let mut fragments: Fragments<[u8]> = Fragments::new()
+            .and_concat(&[1][..])
+            .and_concat(&[2][..])
+            .and_concat(&[3, 4, 5][..])
+            .coalesce_below(2);
+        fragments.compact();
+        assert_eq!("[[1, 2], [3, 4, 5]]", format!("{:?}", fragments));
+        assert_eq!(5, fragments.len());
+    }
+}
@@ -0,0 +1,225 @@
+//! A vector that stores up to `N` items inline, only allocating on the heap once it grows beyond that.
+
+use std::{array, iter, mem, slice, vec};
+
+#[derive(Clone)]
+pub(crate) enum InlineVec<T, const N: usize> {
+    /// The number of items, followed by the items. Exactly the first `len` slots are `Some`.
+    Inline(usize, [Option<T>; N]),
+    Heap(Vec<T>),
+}
+
+pub(crate) type Iter<'a, T> = iter::Chain<iter::Flatten<slice::Iter<'a, Option<T>>>, slice::Iter<'a, T>>;
+pub(crate) type IterMut<'a, T> = iter::Chain<iter::Flatten<slice::IterMut<'a, Option<T>>>, slice::IterMut<'a, T>>;
+pub(crate) type IntoIter<T, const N: usize> = iter::Chain<iter::Flatten<array::IntoIter<Option<T>, N>>, vec::IntoIter<T>>;
+
+impl<T, const N: usize> InlineVec<T, N> {
+    pub fn new() -> Self {
+        InlineVec::Inline(0, empty())
+    }
+
+    /// Creates an `InlineVec` that can hold at least `capacity` items before reallocating. This only
+    /// allocates if `capacity` is greater than `N`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        if capacity <= N {
+            InlineVec::new()
+        } else {
+            InlineVec::Heap(Vec::with_capacity(capacity))
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            InlineVec::Inline(len, _) => *len,
+            InlineVec::Heap(items) => items.len(),
+        }
+    }
+
+    /// The number of items that can be held without reallocating.
+    pub fn capacity(&self) -> usize {
+        match self {
+            InlineVec::Inline(..) => N,
+            InlineVec::Heap(items) => items.capacity(),
+        }
+    }
+
+    /// Whether the items have spilled out onto the heap.
+    #[cfg(test)]
+    pub fn spilled(&self) -> bool {
+        match self {
+            InlineVec::Inline(..) => false,
+            InlineVec::Heap(_) => true,
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        match self {
+            InlineVec::Inline(len, items) if *len < N => {
+                items[*len] = Some(item);
+                *len += 1;
+            }
+            InlineVec::Inline(_, items) => {
+                let mut spilled = Vec::with_capacity(2 * N.max(2));
+                spilled.extend(items.iter_mut().filter_map(Option::take));
+                spilled.push(item);
+                *self = InlineVec::Heap(spilled);
+            }
+            InlineVec::Heap(items) => items.push(item),
+        }
+    }
+
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        match self {
+            InlineVec::Inline(0, _) => None,
+            InlineVec::Inline(_, items) => items[0].as_mut(),
+            InlineVec::Heap(items) => items.first_mut(),
+        }
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        match self {
+            InlineVec::Inline(0, _) => None,
+            InlineVec::Inline(len, items) => items[*len - 1].as_ref(),
+            InlineVec::Heap(items) => items.last(),
+        }
+    }
+
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        match self {
+            InlineVec::Inline(0, _) => None,
+            InlineVec::Inline(len, items) => items[*len - 1].as_mut(),
+            InlineVec::Heap(items) => items.last_mut(),
+        }
+    }
+
+    /// Splits the collection in two at the given index, returning the items from `at` onwards and leaving
+    /// the items before `at` in place. The returned items are stored inline if they fit.
+    ///
+    /// # Panics
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len(), "`at` out of bounds");
+        let mut tail = InlineVec::with_capacity(self.len() - at);
+        match self {
+            InlineVec::Inline(len, items) => {
+                for item in items[at..*len].iter_mut().filter_map(Option::take) {
+                    tail.push(item);
+                }
+                *len = at;
+            }
+            InlineVec::Heap(items) => {
+                for item in items.drain(at..) {
+                    tail.push(item);
+                }
+            }
+        }
+        tail
+    }
+
+    /// Removes the first `count` items, returning them in a new collection. Once the items have spilled
+    /// onto the heap, the remaining items stay in the same buffer.
+    ///
+    /// # Panics
+    /// Panics if `count > len`.
+    pub fn split_to(&mut self, count: usize) -> Self {
+        match self {
+            InlineVec::Inline(..) => {
+                let tail = self.split_off(count);
+                mem::replace(self, tail)
+            }
+            // Drain the front, so that the remaining items keep the existing heap buffer
+            InlineVec::Heap(items) => {
+                assert!(count <= items.len(), "`count` out of bounds");
+                let mut front = InlineVec::with_capacity(count);
+                for item in items.drain(..count) {
+                    front.push(item);
+                }
+                front
+            }
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (inline, heap): (&[Option<T>], &[T]) = match self {
+            InlineVec::Inline(len, items) => (&items[..*len], &[]),
+            InlineVec::Heap(items) => (&[], items),
+        };
+        inline.iter().flatten().chain(heap)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (inline, heap): (&mut [Option<T>], &mut [T]) = match self {
+            InlineVec::Inline(len, items) => (&mut items[..*len], &mut []),
+            InlineVec::Heap(items) => (&mut [], items),
+        };
+        inline.iter_mut().flatten().chain(heap)
+    }
+}
+
+fn empty<T, const N: usize>() -> [Option<T>; N] {
+    array::from_fn(|_| None)
+}
+
+impl<T, const N: usize> Default for InlineVec<T, N> {
+    fn default() -> Self {
+        InlineVec::new()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for InlineVec<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (inline, heap) = match self {
+            InlineVec::Inline(_, items) => (items, Vec::new()),
+            InlineVec::Heap(items) => (empty(), items),
+        };
+        IntoIterator::into_iter(inline).flatten().chain(heap)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a InlineVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InlineVec;
+
+    #[test]
+    fn push_inline_then_spill() {
+        let mut v: InlineVec<u32, 2> = InlineVec::new();
+        v.push(1);
+        v.push(2);
+        assert!(!v.spilled());
+        assert_eq!(vec![&1, &2], v.iter().collect::<Vec<_>>());
+        v.push(3);
+        assert!(v.spilled());
+        assert_eq!(vec![1, 2, 3], v.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn split_to() {
+        let mut v: InlineVec<u32, 4> = InlineVec::with_capacity(6);
+        for i in 0..6 {
+            v.push(i);
+        }
+        let capacity = v.capacity();
+        let front = v.split_to(2);
+        assert!(v.spilled());
+        assert_eq!(capacity, v.capacity());
+        assert!(!front.spilled());
+        assert_eq!(vec![0, 1], front.into_iter().collect::<Vec<_>>());
+        assert_eq!(vec![&2, &3, &4, &5], v.iter().collect::<Vec<_>>());
+        let front = v.split_to(3);
+        assert_eq!(vec![2, 3, 4], front.into_iter().collect::<Vec<_>>());
+        assert_eq!(Some(&mut 5), v.last_mut());
+    }
+}
@@ -1,26 +1,48 @@
+use std::ffi::{OsStr, OsString};
+use std::mem;
+
 /// 
-/// A trait for types whose values have a length, in bytes.
+/// A trait for types whose values have a length.
+/// 
+/// The unit of length is whatever the slice type is indexed by: elements for `Vec<T>` and `[T]`, and bytes
+/// for `String`, `str`, `OsString` and `OsStr`. All of the positions and lengths accepted or returned by 
+/// [`LazyConcat`](::LazyConcat), including ranges passed to [`Sliceable`](::Sliceable), are in these units.
+/// 
+/// An owned type and its slice type must agree, so that `owned.len() == owned.borrow().len()`.
 /// 
-
 pub trait Length 
 {
-    /// The size of the object in bytes
+    /// The size of the object, in units of its slice type.
     fn len(&self) -> usize;
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// The size of the object in bytes. By default this is the same as [`len`](Length::len), which is 
+    /// correct when the unit of length is a byte, but it must be overridden otherwise.
+    fn byte_len(&self) -> usize {
+        self.len()
+    }
 }
 
 impl<T> Length for Vec<T> {
     fn len(&self) -> usize {
         self.len()
     }
+
+    fn byte_len(&self) -> usize {
+        self.len() * mem::size_of::<T>()
+    }
 }
 
 impl<T> Length for [T] {
     fn len(&self) -> usize {
         self.len()
     }
+
+    fn byte_len(&self) -> usize {
+        mem::size_of_val(self)
+    }
 }
 
 impl Length for String {
@@ -34,3 +56,15 @@ impl Length for str {
         self.len()
     }
 }
+
+impl Length for OsString {
+    fn len(&self) -> usize {
+        self.as_os_str().len()
+    }
+}
+
+impl Length for OsStr {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
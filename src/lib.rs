@@ -30,125 +30,354 @@
 //! assert_eq!(&[2, 3, 4, 5], slice);
 //! ```
 //! 
+//! # Supporting other types
 //! 
+//! A [`LazyConcat`] can be built on any owned root type `T` with a borrowed slice type `B`, given 
+//! implementations of these traits:
+//! 
+//!  * [`Length`] for both `T` and `B`, measured in the units that `B` is indexed by.
+//!  * [`Concat<Cow<B>>`](Concat) and [`Prepend`] for `T`, to concatenate fragments.
+//!  * [`Reserve`] for `T`, to grow the root once before normalizing.
+//!  * [`Sliceable`] for `T`, and optionally [`SliceableMut`], [`SplitOff`] and [`Elements`], to enable
+//!    slicing, splitting and iteration.
+//! 
+//! Use [`check_impls`] or the [`lazy_concat_test_suite!`] macro in tests to check that the 
+//! implementations agree with each other.
+//! 
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
 use std::{
     borrow::{Cow, Borrow},
     fmt::{self, Debug, Formatter},
     iter,
     mem,
-    ops::{Bound, RangeBounds},
+    ops::{Add, AddAssign, RangeBounds},
 };
 
 pub(crate) mod concat;
 pub(crate) mod length;
 pub(crate) mod sliceable;
+pub(crate) mod reserve;
+pub(crate) mod inline_vec;
+pub(crate) mod error;
+pub(crate) mod fragments;
+pub(crate) mod to_lazy_concat;
+pub(crate) mod split_off;
+pub(crate) mod cmp;
+pub(crate) mod elements;
+pub(crate) mod memory_stats;
+pub(crate) mod policy;
+#[macro_use]
+pub(crate) mod check;
+#[cfg(feature = "parallel")]
+pub(crate) mod parallel;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_impls;
 
 pub use length::Length;
-pub use concat::Concat;
-pub use sliceable::Sliceable;
+pub use concat::{Concat, Prepend};
+pub use sliceable::{Sliceable, SliceableMut};
+pub use reserve::Reserve;
+pub use error::LazyConcatError;
+pub use fragments::Fragments;
+pub use to_lazy_concat::ToLazyConcat;
+pub use split_off::SplitOff;
+pub use elements::Elements;
+pub use check::check_impls;
+pub use memory_stats::MemoryStats;
+pub use policy::NormalizePolicy;
+#[cfg(feature = "serde")]
+pub use serde_impls::serde_fragments;
 
-pub struct LazyConcat<'a, T, B> 
+
+/// A lazily concatenated collection, made of a normalized root and a list of fragments.
+/// 
+/// Up to `N` fragments are stored inline, without any heap allocation for the list of fragments. 
+/// Use [`new_inline`](LazyConcat::new_inline) to choose a different `N`.
+pub struct LazyConcat<'a, T, B, const N: usize = 4> 
 where 
     B: ?Sized + 'a + ToOwned
 {
     root: T,
-    fragments: Vec<Fragment<'a, B>>,
+    fragments: Fragments<'a, B, N>,
+    limits: Limits,
+    policy: NormalizePolicy,
+    chars_counted: CharCount,
 }
 
-pub(crate) enum Fragment<'a, B> 
-where 
-    B: ?Sized + 'a + ToOwned
-{
-    Value(Cow<'a, B>),
+/// The number of `char`s in the first `len` units of the data, cached by 
+/// [`char_len`](LazyConcat::char_len). Concatenating doesn't change this, so it only needs to be reset 
+/// when existing data is mutated or removed.
+#[derive(Debug, Clone, Copy, Default)]
+struct CharCount {
+    len: usize,
+    chars: usize,
 }
 
-impl<'a, B: 'a> Fragment<'a, B> 
-where 
-    B: ToOwned + ?Sized,
-{
-    #[inline]
-    fn get(self) -> Cow<'a, B> {
-        match self {
-            Fragment::Value(b) => b,
-        }
-    }
-
-    #[inline]
-    fn borrow(&self) -> &B {
-        match self {
-            Fragment::Value(ref b) => b.borrow()
-        }
-    }
+/// Limits on the size of a [`LazyConcat`], which are checked when concatenating.
+#[derive(Debug, Clone, Copy, Default)]
+struct Limits {
+    max_len: Option<usize>,
+    max_fragments: Option<usize>,
+}
 
-    fn len(&self) -> usize 
+impl Limits {
+    /// Check that a fragment of length `len` can be concatenated.
+    fn check<'a, B, const N: usize>(&self, root_len: usize, fragments: &Fragments<'a, B, N>, len: usize) -> Result<(), LazyConcatError>
     where
-        B: Length,
+        B: ToOwned + ?Sized + Length,
+        B::Owned: Concat<Cow<'a, B>> + Default,
     {
-        self.borrow().len()
+        let added = if fragments.absorbs(len) { 0 } else { 1 };
+        let len = root_len.saturating_add(fragments.len()).saturating_add(len);
+        self.check_totals(len, fragments.num_fragments() + added)
     }
-}
 
-impl<'a, B> Debug for Fragment<'a, B> 
-where 
-    B: ToOwned + 'a,
-    B: Debug + ?Sized,
-{
-    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-        self.borrow().fmt(f)
+    /// Check that the total length and number of fragments, after concatenating, are within the limits.
+    fn check_totals(&self, len: usize, num_fragments: usize) -> Result<(), LazyConcatError> {
+        if let Some(max) = self.max_len {
+            if len > max {
+                return Err(LazyConcatError::LengthLimitExceeded { len, max });
+            }
+        }
+        if let Some(max) = self.max_fragments {
+            if num_fragments > max {
+                return Err(LazyConcatError::FragmentLimitExceeded { max });
+            }
+        }
+        Ok(())
     }
 }
 
 impl<'a, T, B> LazyConcat<'a, T, B> 
 where
-    // TODO Remove Default requirement. This could be done by putting root in an Option perhaps.
-    T: Concat<Cow<'a, B>> + Borrow<B> + Default + Length,
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
     B: ToOwned<Owned = T> + ?Sized + Length,
 {
     /// Construct a new [`LazyConcat`]. The initial value should be an owned value, such as a `Vec` or 
     /// a `String`. This can be empty but it doesn't have to be.
     pub fn new(initial: T) -> Self {
-        LazyConcat { root: initial, fragments: Vec::new() }
+        LazyConcat::new_inline(initial)
+    }
+
+    /// Construct a new [`LazyConcat`] with an empty root.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let lz = LazyConcat::new_empty()
+    ///     .and_concat(&[1, 2][..]);
+    /// assert_eq!(vec![1, 2], lz.done());
+    /// ```
+    pub fn new_empty() -> Self {
+        LazyConcat::new(T::default())
     }
     
     /// Construct a new [`LazyConcat`], but preallocate the vector of fragments with the expected number
-    /// of fragments, so that won't need to be reallocated as fragments are added.
+    /// of fragments, so that won't need to be reallocated as fragments are added. Nothing is allocated if 
+    /// `n` fragments fit in the inline storage.
     pub fn expecting_num_fragments(initial: T, n: usize) -> Self {
-        LazyConcat { root: initial, fragments: Vec::with_capacity(n) }
+        LazyConcat { fragments: Fragments::with_capacity(n), ..LazyConcat::new(initial) }
+    }
+}
+
+impl<'a, T, B, const N: usize> LazyConcat<'a, T, B, N> 
+where
+    // TODO Remove Default requirement. This could be done by putting root in an Option perhaps.
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+    B: ToOwned<Owned = T> + ?Sized + Length,
+{
+    /// Construct a new [`LazyConcat`] which stores up to `N` fragments inline, only allocating a list on the 
+    /// heap if more fragments than that are concatenated.
+    /// 
+    /// The inline storage makes the `LazyConcat` itself larger, and a larger `N` makes it larger still. With
+    /// the default `N` of 4, `size_of::<LazyConcat<String, str>>()` is 216 bytes on a 64-bit target, where 
+    /// it was 48 bytes before fragments were stored inline. Box it if it has to be moved around a lot.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let lz = LazyConcat::<_, _, 8>::new_inline(String::new())
+    ///     .and_concat("a")
+    ///     .and_concat("b");
+    /// assert_eq!("ab", lz.done());
+    /// ```
+    pub fn new_inline(initial: T) -> Self {
+        LazyConcat { 
+            root: initial, 
+            fragments: Fragments::new(), 
+            limits: Limits::default(),
+            policy: NormalizePolicy::Never,
+            chars_counted: CharCount::default(),
+        }
+    }
+
+    /// Set a coalescing policy, so that any fragment shorter than `len` is merged into an owned tail fragment
+    /// when it is concatenated, instead of being added to the list of fragments. This keeps the list short
+    /// when many tiny fragments are concatenated, at the cost of copying those fragments.
+    /// 
+    /// The default is `0`, which disables coalescing.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let lz = LazyConcat::new(String::new())
+    ///     .coalesce_below(4)
+    ///     .and_concat("a")
+    ///     .and_concat("b")
+    ///     .and_concat("long fragment")
+    ///     .and_concat("c");
+    /// assert_eq!("LazyConcat { \"\", \"ab\", \"long fragment\", \"c\" }", format!("{:?}", lz));
+    /// ```
+    pub fn coalesce_below(mut self, len: usize) -> Self {
+        self.fragments.coalesce_below = len;
+        self
+    }
+
+    /// Limit the total length, including the root and all fragments. Concatenating a fragment that would
+    /// exceed the limit fails with [`LazyConcatError::LengthLimitExceeded`]. Lengths are measured with 
+    /// [`Length`], so no normalization is needed to enforce this.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::{LazyConcat, LazyConcatError};
+    /// let mut lz = LazyConcat::new(String::from("abc"))
+    ///     .limit_len(8);
+    /// assert_eq!(Ok(()), lz.try_concat("defgh"));
+    /// assert_eq!(Err(LazyConcatError::LengthLimitExceeded { len: 9, max: 8 }), lz.try_concat("i"));
+    /// ```
+    pub fn limit_len(mut self, max: usize) -> Self {
+        self.limits.max_len = Some(max);
+        self
+    }
+
+    /// Limit the number of fragments. Concatenating a fragment that would exceed the limit fails with 
+    /// [`LazyConcatError::FragmentLimitExceeded`], unless it would be merged into the previous fragment
+    /// according to the policy set with [`coalesce_below`](LazyConcat::coalesce_below).
+    pub fn limit_fragments(mut self, max: usize) -> Self {
+        self.limits.max_fragments = Some(max);
+        self
+    }
+
+    /// Set a policy for normalizing automatically when fragments are concatenated or appended, so that 
+    /// the overhead of keeping fragments stays bounded. The default is [`NormalizePolicy::Never`].
+    /// 
+    /// If the memory for an automatic normalization cannot be allocated then the fragments are left as 
+    /// they are, rather than failing the concatenation. Concatenating through 
+    /// [`split_normalized`](LazyConcat::split_normalized) never normalizes, because the root is borrowed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::{LazyConcat, NormalizePolicy};
+    /// let mut lz = LazyConcat::new(String::new())
+    ///     .normalize_policy(NormalizePolicy::FragmentsAbove(2));
+    /// lz.concat("a");
+    /// lz.concat("b");
+    /// assert_eq!(0, lz.get_normalized_len());
+    /// lz.concat("c");
+    /// assert_eq!(3, lz.get_normalized_len());
+    /// ```
+    pub fn normalize_policy(mut self, policy: NormalizePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Normalize if the policy set with [`normalize_policy`](LazyConcat::normalize_policy) requires it.
+    fn apply_policy(&mut self) {
+        if self.policy.should_normalize(&self.fragments) {
+            // A failure leaves the fragments intact, so they can still be normalized later
+            let _ = self.try_normalize();
+        }
+    }
+
+    /// Merge adjacent fragments that are shorter than the threshold set with 
+    /// [`coalesce_below`](LazyConcat::coalesce_below), following the same rules that are applied when 
+    /// concatenating. The merged fragments are not normalized into the root.
+    pub fn compact(&mut self) {
+        self.fragments.compact();
     }
 
     /// Fully normalize the collection by concatenating every fragament onto the base.
     pub fn normalize(&mut self) {
-        self.normalize_range(..);
+        let count = self.fragments.num_fragments();
+        self.normalize_fragments(count);
+    }
+
+    /// Fully normalize the collection, like [`normalize`](LazyConcat::normalize), but return
+    /// [`LazyConcatError::AllocationFailure`] instead of aborting if the memory for the normalized data 
+    /// cannot be allocated. In that case, no fragments are normalized. A fragment that is still shared with a 
+    /// [`fork`](LazyConcat::fork) is copied with an ordinary, infallible allocation.
+    pub fn try_normalize(&mut self) -> Result<(), LazyConcatError> {
+        let count = self.fragments.num_fragments();
+        self.try_normalize_fragments(count)
+    }
+
+    fn normalize_fragments(&mut self, count: usize) {
+        self.fragments.normalize_fragments(&mut self.root, count);
     }
 
-    fn normalize_range<R: RangeBounds<usize>>(&mut self, range: R) {
-        let fragments = self.fragments.drain(range);
-        let root = mem::replace(&mut self.root, Default::default());
-        self.root = fragments.fold(root, |agg, frag| agg.concat(frag.get()));
+    fn try_normalize_fragments(&mut self, count: usize) -> Result<(), LazyConcatError> {
+        self.fragments.try_normalize_fragments(&mut self.root, count)
     }
 
     /// Normalize at least `len` elements and return the number of elements that were actually normalized.
     /// This could fail if there are not enough fragments to make up the required length, in which case
     /// `None` is returned and no work is done.
     pub fn normalize_to_len(&mut self, len: usize) -> Option<usize> {
-        if self.root.len() >= len {
-            Some(self.root.len())
-        } else if let Some(num) = self.fragments
-            .iter()
-            .scan(0, |total, ref fragment| {
-                *total += fragment.len();
-                Some(*total)
-            })
-            .position(|s| s >= len - self.root.len()) 
-        {
-            self.normalize_range(..=num);
-            Some(self.root.len())
-        } else {
-            None
-        }
+        self.fragments.normalize_to_len(&mut self.root, len)
+    }
+
+    /// Normalize at least `len` elements, like [`normalize_to_len`](LazyConcat::normalize_to_len), but 
+    /// return [`LazyConcatError::OutOfBounds`] if there are not enough fragments to make up the required 
+    /// length, or [`LazyConcatError::AllocationFailure`] if the memory for the normalized data cannot be 
+    /// allocated. No fragments are normalized in either case.
+    pub fn try_normalize_to_len(&mut self, len: usize) -> Result<usize, LazyConcatError> {
+        self.fragments.try_normalize_to_len(&mut self.root, len)
+    }
+
+    /// The length of all of the data, including fragments that are not yet normalized.
+    fn total_len(&self) -> usize {
+        self.root.len() + self.fragments.len()
     }
 
-    /// The amount of data (in bytes) that has already been normalized. This is the maximum length 
+    /// The length of all of the data, including fragments that are not yet normalized, measured with 
+    /// [`Length`]. This is the number of elements for a `Vec` and the number of bytes for a `String`, and 
+    /// is the unit used by every position and range passed to a `LazyConcat`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let lz = LazyConcat::new(vec![1u64, 2]).and_concat(&[3][..]);
+    /// assert_eq!(3, lz.len());
+    /// assert_eq!(24, lz.byte_len());
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.total_len()
+    }
+
+    /// Whether there is no data, either normalized or in fragments.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.total_len() == 0
+    }
+
+    /// The size of all of the data in bytes, including fragments that are not yet normalized. This is 
+    /// calculated with [`Length::byte_len`] and does not include any unused capacity.
+    pub fn byte_len(&self) -> usize {
+        self.root.byte_len() + self.fragments.iter().map(Length::byte_len).sum::<usize>()
+    }
+
+    /// The amount of data, in units of [`Length`], that has already been normalized. This is the maximum length 
     /// of a slice that can be taken without first calling [`normalize`](LazyConcat::normalize) or 
     /// [`normalize_to_len`](LazyConcat::normalize_to_len).
     #[inline]
@@ -156,7 +385,13 @@ where
         self.root.len()
     }
 
-    /// Checks if any normalization is required before taking a slice
+    /// Checks if any normalization is required before taking a slice. This is `true` only if the end of the 
+    /// range is beyond the normalized data, but within the total length, so that normalizing would make 
+    /// the slice available. A range that ends beyond the total length can never be sliced, so it doesn't
+    /// need normalization.
+    /// 
+    /// [`ensure_normalized`](LazyConcat::ensure_normalized) does this check, normalizes as needed and
+    /// returns the slice, all in one call.
     /// 
     /// # Examples
     /// ```
@@ -164,20 +399,124 @@ where
     /// # let mut lz = LazyConcat::new(Vec::new())
     /// #   .and_concat(&[0,1,2,3,4,5,6][..]);
     /// if lz.slice_needs_normalization(1..3) {
-    ///     lz.normalize_to_len(4);
+    ///     lz.normalize_to_len(3);
     /// }
     /// let slice = lz.get_slice(1..3);
     /// ```
     /// 
     #[inline]
-    pub fn slice_needs_normalization<R: RangeBounds<usize>>(&mut self, range: R) -> bool {
-        match range.end_bound() {
-            Bound::Unbounded => self.fragments.len() == 0,
-            Bound::Excluded(&n) => self.root.len() < n,
-            Bound::Included(&n) => self.root.len() <= n,
+    pub fn slice_needs_normalization<R: RangeBounds<usize>>(&self, range: R) -> bool {
+        match sliceable::try_range(self.total_len(), range) {
+            Ok((_, end)) => self.root.len() < end,
+            Err(_) => false,
+        }
+    }
+
+    /// Normalize just enough of the data for the range to be sliced, and return the slice. This returns an 
+    /// error if the range can't be sliced, even after normalizing, or if the memory for the normalized data
+    /// can't be allocated. Nothing is normalized if the range is out of bounds or the allocation fails. A
+    /// [`NotCharBoundary`](LazyConcatError::NotCharBoundary) error is only detected after normalizing, so 
+    /// in that case the data is left normalized up to the end of the range.
+    /// 
+    /// # Examples
+    /// ```
+    /// # use lazy_concat::{LazyConcat, LazyConcatError};
+    /// let mut lz = LazyConcat::new(String::new())
+    ///     .and_concat("Hello")
+    ///     .and_concat(" ")
+    ///     .and_concat("there!");
+    /// assert_eq!(Ok("ell"), lz.ensure_normalized(1..4));
+    /// assert_eq!(Err(LazyConcatError::OutOfBounds { end: 13, len: 12 }), lz.ensure_normalized(6..13));
+    /// assert_eq!("LazyConcat { \"Hello\", \" \", \"there!\" }", format!("{:?}", lz));
+    /// 
+    /// let mut lz = LazyConcat::new(String::new())
+    ///     .and_concat("形")
+    ///     .and_concat("聲");
+    /// assert_eq!(Err(LazyConcatError::NotCharBoundary { index: 1 }), lz.ensure_normalized(1..3));
+    /// assert_eq!(3, lz.get_normalized_len());
+    /// ```
+    pub fn ensure_normalized<R>(&mut self, range: R) -> Result<&B, LazyConcatError>
+    where
+        R: RangeBounds<usize>,
+        T: Sliceable<Slice = B>,
+    {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        let (_, end) = sliceable::try_range(self.total_len(), range)?;
+        self.try_normalize_to_len(end)?;
+        self.try_get_slice(range)
+    }
+
+    /// Normalize just enough of the data for the range to be sliced, and return the slice. This is the same 
+    /// as [`ensure_normalized`](LazyConcat::ensure_normalized), except that it panics instead of returning 
+    /// an error.
+    /// 
+    /// # Examples
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut lz = LazyConcat::new(Vec::new())
+    ///     .and_concat(&[0, 1, 2][..])
+    ///     .and_concat(&[3, 4, 5][..]);
+    /// assert_eq!(&[2, 3], lz.slice(2..4));
+    /// ```
+    /// # Panics
+    /// Panics when the range falls outside the data, even after normalizing.
+    pub fn slice<R>(&mut self, range: R) -> &B
+    where
+        R: RangeBounds<usize>,
+        T: Sliceable<Slice = B>,
+    {
+        match self.ensure_normalized(range) {
+            Ok(slice) => slice,
+            Err(e) => panic!("{}", e),
         }
     }
 
+    /// Normalize just enough of the data for the range to be sliced, and return a mutable slice. This is the 
+    /// same as [`slice`](LazyConcat::slice) except that the slice is mutable.
+    /// 
+    /// # Examples
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut lz = LazyConcat::new(vec![0, 1])
+    ///     .and_concat(&[2, 3][..])
+    ///     .and_concat(&[4][..]);
+    /// lz.slice_mut(1..3).copy_from_slice(&[10, 20]);
+    /// assert_eq!(vec![0, 10, 20, 3, 4], lz.done());
+    /// ```
+    /// # Panics
+    /// Panics when the range falls outside the data, even after normalizing.
+    pub fn slice_mut<R>(&mut self, range: R) -> &mut B
+    where
+        R: RangeBounds<usize>,
+        T: SliceableMut<Slice = B>,
+    {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        let (_, end) = match sliceable::try_range(self.total_len(), range) {
+            Ok(bounds) => bounds,
+            Err(e) => panic!("{}", e),
+        };
+        self.normalize_to_len(end);
+        self.chars_counted = CharCount::default();
+        self.root.get_slice_mut(range)
+    }
+
+    /// Fully normalize the collection and borrow all of the data. Unlike [`done`](LazyConcat::done), this 
+    /// does not consume `self`, so more fragments can be concatenated afterwards.
+    /// 
+    /// # Examples
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut lz = LazyConcat::new(String::from("abc"))
+    ///     .and_concat("def");
+    /// assert_eq!("abcdef", lz.as_normalized());
+    /// lz.concat("ghi");
+    /// assert_eq!("abcdefghi", lz.done());
+    /// ```
+    pub fn as_normalized(&mut self) -> &B {
+        self.normalize();
+        self.root.borrow()
+    }
+
     /// Get a slice from the normalized data. Before calling this method you should check that the size of
     /// the normalized data is sufficient to be able to support this slice and, if necessary normalizing 
     /// the data to the required size using [`normalize_to_len`](LazyConcat::normalize_to_len).
@@ -191,13 +530,99 @@ where
         self.root.get_slice(range)
     }
 
-    fn fragments_iter(&self) -> impl Iterator<Item = &B>
+    /// Get a mutable slice from the normalized data. Before calling this method you should make sure that
+    /// enough of the data has been normalized, using [`normalize_to_len`](LazyConcat::normalize_to_len), or 
+    /// use [`slice_mut`](LazyConcat::slice_mut) instead.
+    /// 
+    /// # Examples
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut lz = LazyConcat::new(String::from("hello"))
+    ///     .and_concat(" there");
+    /// lz.get_slice_mut(..1).make_ascii_uppercase();
+    /// assert_eq!("Hello there", lz.done());
+    /// ```
+    /// # Panics
+    /// Panics when the range falls outside the size of the owned data.
+    pub fn get_slice_mut<R>(&mut self, range: R) -> &mut B
+    where
+        R: RangeBounds<usize>,
+        T: SliceableMut<Slice = B>,
+    {
+        self.chars_counted = CharCount::default();
+        self.root.get_slice_mut(range)
+    }
+
+    /// Mutably borrow the normalized root. Fragments are always concatenated onto the end of the root, so 
+    /// any changes to its contents, including its length, are kept when the rest is normalized. 
+    /// 
+    /// Limits set with [`limit_len`](LazyConcat::limit_len) are not enforced for changes made this way.
+    /// 
+    /// # Examples
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut lz = LazyConcat::new(vec![0, 0])
+    ///     .and_concat(&[7, 8, 9][..]);
+    /// // Patch a length header
+    /// lz.root_mut()[0] = 3;
+    /// assert_eq!(vec![3, 0, 7, 8, 9], lz.done());
+    /// ```
+    pub fn root_mut(&mut self) -> &mut T {
+        self.chars_counted = CharCount::default();
+        &mut self.root
+    }
+
+    /// Get a slice from the normalized data, like [`get_slice`](LazyConcat::get_slice), but return an error 
+    /// instead of panicking if the range is not valid. If the range lies within the data but extends past the
+    /// normalized part, the error is [`LazyConcatError::NotNormalized`]. For strings, it is also an error if 
+    /// either end of the range is not on a `char` boundary.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::{LazyConcat, LazyConcatError};
+    /// let lz = LazyConcat::new(String::from("形聲"))
+    ///     .and_concat("網");
+    /// assert_eq!(Ok("形"), lz.try_get_slice(0..3));
+    /// assert_eq!(Err(LazyConcatError::NotCharBoundary { index: 1 }), lz.try_get_slice(1..3));
+    /// assert_eq!(Err(LazyConcatError::NotNormalized { end: 9, normalized_len: 6 }), lz.try_get_slice(..9));
+    /// assert_eq!(Err(LazyConcatError::OutOfBounds { end: 10, len: 9 }), lz.try_get_slice(..10));
+    /// ```
+    pub fn try_get_slice<R>(&self, range: R) -> Result<&B, LazyConcatError>
     where
-        T: Sliceable<Slice = B>
+        R: RangeBounds<usize>,
+        T: Sliceable<Slice = B>,
     {
-        iter::once(self.root.get_slice(..))
-            .chain(self.fragments.iter()
-            .map(Fragment::borrow))
+        let (start, end) = sliceable::try_range(self.total_len(), range)?;
+        let normalized_len = self.root.len();
+        if end > normalized_len {
+            Err(LazyConcatError::NotNormalized { end, normalized_len })
+        } else {
+            self.root.try_get_slice(start .. end)
+        }
+    }
+
+    fn fragments_iter(&self) -> impl Iterator<Item = &B> {
+        iter::once(self.root.borrow())
+            .chain(self.fragments.iter())
+    }
+
+    /// Creates an iterator over the elements of the root and every fragment, as defined by the 
+    /// [`Elements`] implementation of the slice type. No normalization needs to be done for this to work.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let lz = LazyConcat::new(String::from("a"))
+    ///     .and_concat("bc");
+    /// assert_eq!(vec!['a', 'b', 'c'], lz.elements().collect::<Vec<_>>());
+    /// ```
+    pub fn elements(&self) -> impl Iterator<Item = B::Item<'_>>
+    where
+        B: Elements,
+    {
+        self.fragments_iter().flat_map(B::elements)
     }
 
     /// Consume the LazyConcat, concatenate all of the fragments and return the owned, fully normalized data.
@@ -219,6 +644,15 @@ where
         self.root
     }
 
+    /// Consume the LazyConcat and return the owned, fully normalized data, like [`done`](LazyConcat::done),
+    /// but return [`LazyConcatError::AllocationFailure`] instead of aborting if the memory cannot be 
+    /// allocated. To keep hold of the data when this fails, use [`try_normalize`](LazyConcat::try_normalize) 
+    /// first instead. As with `try_normalize`, fragments still shared with a fork are copied infallibly.
+    pub fn try_done(mut self) -> Result<T, LazyConcatError> {
+        self.try_normalize()?;
+        Ok(self.root)
+    }
+
     /// Lazily concatenate an owned or borrowed fragment of data. No data will be moved or copied until the
     /// next time that [`normalize`](LazyConcat::normalize) or [`normalize_to_len`](LazyConcat::normalize_to_len) is called.
     /// 
@@ -232,15 +666,156 @@ where
     /// Lazily concatenate an owned or borrowed fragment of data. No data will be moved or copied until the
     /// next time that [`normalize`](LazyConcat::normalize) or [`normalize_to_len`](LazyConcat::normalize_to_len) 
     /// is called.
+    /// 
+    /// Short fragments may be merged into the previous one, according to the policy set with
+    /// [`coalesce_below`](LazyConcat::coalesce_below).
+    /// 
+    /// # Panics
+    /// Panics if a limit set with [`limit_len`](LazyConcat::limit_len) or 
+    /// [`limit_fragments`](LazyConcat::limit_fragments) would be exceeded. Use 
+    /// [`try_concat`](LazyConcat::try_concat) to handle this instead.
     pub fn concat<F: Into<Cow<'a, B>>>(&mut self, fragment: F) {
-        self.fragments.push(Fragment::Value(fragment.into()));
+        if let Err(e) = self.try_concat(fragment) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Lazily concatenate an owned or borrowed fragment of data, like [`concat`](LazyConcat::concat), but
+    /// return an error if a limit set with [`limit_len`](LazyConcat::limit_len) or 
+    /// [`limit_fragments`](LazyConcat::limit_fragments) would be exceeded. The fragment is not concatenated
+    /// in that case.
+    pub fn try_concat<F: Into<Cow<'a, B>>>(&mut self, fragment: F) -> Result<(), LazyConcatError> {
+        let fragment = fragment.into();
+        self.limits.check(self.root.len(), &self.fragments, fragment.len())?;
+        self.fragments.concat(fragment);
+        self.apply_policy();
+        Ok(())
+    }
+
+    /// Move all of the data of `other` onto the end of this `LazyConcat`, without normalizing or copying 
+    /// any of it. The root of `other` becomes an owned fragment, followed by the fragments of `other`.
+    /// 
+    /// # Panics
+    /// Panics if a limit set with [`limit_len`](LazyConcat::limit_len) or 
+    /// [`limit_fragments`](LazyConcat::limit_fragments) would be exceeded. Use 
+    /// [`try_append`](LazyConcat::try_append) to handle this instead.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut a = LazyConcat::new(String::from("ab")).and_concat("c");
+    /// let b = LazyConcat::new(String::from("de")).and_concat("f");
+    /// a.append(b);
+    /// assert_eq!(2, a.get_normalized_len());
+    /// assert_eq!("abcdef", a.done());
+    /// ```
+    pub fn append<const M: usize>(&mut self, other: LazyConcat<'a, T, B, M>) {
+        if let Err(e) = self.try_append(other) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Move all of the data of `other` onto the end of this `LazyConcat`, like 
+    /// [`append`](LazyConcat::append), but return an error if a limit would be exceeded, in which case 
+    /// nothing is appended. The fragment limit is checked against the number of fragments before any 
+    /// coalescing.
+    pub fn try_append<const M: usize>(&mut self, other: LazyConcat<'a, T, B, M>) -> Result<(), LazyConcatError> {
+        let LazyConcat { root, mut fragments, .. } = other;
+        let len = self.total_len().saturating_add(root.len()).saturating_add(fragments.len());
+        let root_fragments = if root.is_empty() { 0 } else { 1 };
+        let num_fragments = self.fragments.num_fragments() + root_fragments + fragments.num_fragments();
+        self.limits.check_totals(len, num_fragments)?;
+        if !root.is_empty() {
+            self.fragments.concat(Cow::Owned(root));
+        }
+        self.fragments.append(&mut fragments);
+        self.apply_policy();
+        Ok(())
+    }
+
+    /// Split the `LazyConcat` in two at a position in the concatenated data, returning everything from 
+    /// `at` onwards and leaving everything before `at` in place. Nothing is normalized: if `at` is within 
+    /// the root then the root is split, otherwise the fragments are divided, and a borrowed fragment 
+    /// spanning `at` is split into two borrows. The returned `LazyConcat` has the same limits and 
+    /// coalescing policy.
+    /// 
+    /// # Panics
+    /// Panics if `at` is greater than the total length, or if it does not fall on a valid position at 
+    /// which to split the data, such as a `char` boundary.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut lz = LazyConcat::new(String::from("ab"))
+    ///     .and_concat("cde")
+    ///     .and_concat("f");
+    /// let tail = lz.split_off(3);
+    /// assert_eq!("abc", lz.done());
+    /// assert_eq!("def", tail.done());
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        T: SplitOff,
+        B: Sliceable<Slice = B>,
+    {
+        let root_len = self.root.len();
+        let (root, fragments) = if at <= root_len {
+            let coalesce_below = self.fragments.coalesce_below;
+            let fragments = mem::replace(&mut self.fragments, Fragments::new().coalesce_below(coalesce_below));
+            (self.root.split_off(at), fragments)
+        } else {
+            (T::default(), self.fragments.split_off(at - root_len))
+        };
+        if at < self.chars_counted.len {
+            self.chars_counted = CharCount::default();
+        }
+        LazyConcat { 
+            root, 
+            fragments, 
+            limits: self.limits, 
+            policy: self.policy, 
+            chars_counted: CharCount::default(),
+        }
+    }
+
+    /// Make a copy of this `LazyConcat` which shares its data instead of copying it, so that the two can 
+    /// diverge cheaply. The root and any owned fragments are moved into reference counted fragments, 
+    /// which both copies share, and borrowed fragments are copied as references. Shared data is only 
+    /// copied if it is mutated or normalized while still shared.
+    /// 
+    /// Because the root becomes a fragment, nothing remains normalized in either copy.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut header = LazyConcat::new(String::from("Header\n"));
+    /// let a = header.fork().and_concat("first body");
+    /// let b = header.and_concat("second body");
+    /// assert_eq!("Header\nfirst body", a.done());
+    /// assert_eq!("Header\nsecond body", b.done());
+    /// ```
+    pub fn fork(&mut self) -> Self {
+        if !self.root.is_empty() {
+            let root = mem::take(&mut self.root);
+            self.fragments.push_front(root);
+        }
+        LazyConcat { 
+            root: T::default(), 
+            fragments: self.fragments.fork(), 
+            limits: self.limits, 
+            policy: self.policy,
+            chars_counted: self.chars_counted,
+        }
     }
 
     /// Splits the `LazyConcat` into two parts:
     /// 
     ///  * An immutable borrow of the normalized concatenation of the root.
     ///  * A mutable view, [`ConcatOnly`], which permits further lazy concatenation, using 
-    /// [`concat`](LazyConcat::concat), but no other mutation.
+    ///    [`concat`](LazyConcat::concat), but no other mutation.
     /// 
     /// This lets you keep hold of a slice into the normalized root, while still allowing further concatenation
     /// of fragments. This would not otherwise be possible because [`and_concat`](LazyConcat::and_concat) consumes 
@@ -267,15 +842,38 @@ where
     /// assert_eq!(vec![0,1,2,3,4,99], lz.done());
     /// ```
     ///
-    pub fn split_normalized<'b>(&'b mut self) -> (&'b B, ConcatOnly<&'b mut LazyConcat<'a, T, B>>)
-    where
-        T: Sliceable<Slice = B>,
-    {
-        // This is safe because:
-        //  1. The returned slice into self.root is immutable
-        //  2. ConcatOnly is mutable but only has one method, which does move or mutate the slice 
-        let norm = unsafe { &*self.root.as_ptr() };
-        (norm, ConcatOnly(self))
+    pub fn split_normalized<'b>(&'b mut self) -> (&'b B, ConcatOnly<'b, 'a, B, N>) {
+        let LazyConcat { root, fragments, limits, .. } = self;
+        let concat_only = ConcatOnly { root_len: root.len(), fragments, limits: *limits };
+        ((*root).borrow(), concat_only)
+    }
+
+    /// Splits the `LazyConcat` into an immutable borrow of the normalized root and a mutable borrow of 
+    /// its pending [`Fragments`]. Unlike [`split_normalized`](LazyConcat::split_normalized), fragments can 
+    /// be removed or compacted as well as concatenated, and the limits set with 
+    /// [`limit_len`](LazyConcat::limit_len) and [`limit_fragments`](LazyConcat::limit_fragments) are 
+    /// not enforced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut lz = LazyConcat::new(String::from("Hello"))
+    ///     .and_concat(", ");
+    /// {
+    ///     let (root, fragments) = lz.split_root();
+    ///     fragments.concat(root.to_uppercase());
+    /// }
+    /// assert_eq!("Hello, HELLO", lz.done());
+    /// ```
+    pub fn split_root(&mut self) -> (&T, &mut Fragments<'a, B, N>) {
+        self.chars_counted = CharCount::default();
+        (&self.root, &mut self.fragments)
+    }
+
+    /// The fragments which have been concatenated but not yet normalized.
+    pub fn fragments(&self) -> &Fragments<'a, B, N> {
+        &self.fragments
     }
 }
 
@@ -283,31 +881,80 @@ where
 /// normalization.
 /// 
 /// This `struct` is created by the [`split_normalized`](`LazyConcat::split_normalized`) method.
-pub struct ConcatOnly<T>(T);
+pub struct ConcatOnly<'b, 'a, B, const N: usize = 4>
+where
+    B: ?Sized + 'a + ToOwned,
+{
+    fragments: &'b mut Fragments<'a, B, N>,
+    root_len: usize,
+    limits: Limits,
+}
 
-impl<'a, 'b, T, B> ConcatOnly<&'b mut LazyConcat<'a, T, B>>
+impl<'b, 'a, B, const N: usize> ConcatOnly<'b, 'a, B, N>
 where
-    T: Concat<Cow<'a, B>> + Borrow<B> + Default + Length,
-    B: ToOwned<Owned = T> + ?Sized + Length,
+    B: ToOwned + ?Sized + Length,
+    B::Owned: Concat<Cow<'a, B>> + Default,
 {
+    /// Lazily concatenate an owned or borrowed fragment, like [`LazyConcat::concat`].
+    ///
+    /// # Panics
+    /// Panics if a limit set on the [`LazyConcat`] would be exceeded.
     pub fn concat<F: Into<Cow<'a, B>>>(&mut self, fragment: F) {   
-        self.0.concat(fragment);
+        if let Err(e) = self.try_concat(fragment) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Lazily concatenate an owned or borrowed fragment, like [`LazyConcat::try_concat`].
+    pub fn try_concat<F: Into<Cow<'a, B>>>(&mut self, fragment: F) -> Result<(), LazyConcatError> {
+        let fragment = fragment.into();
+        self.limits.check(self.root_len, self.fragments, fragment.len())?;
+        self.fragments.concat(fragment);
+        Ok(())
     }
 
-    pub fn and_concat<F: Into<Cow<'a, B>>>(self, fragment: F) -> Self {
-        self.0.concat(fragment);
+    pub fn and_concat<F: Into<Cow<'a, B>>>(mut self, fragment: F) -> Self {
+        self.concat(fragment);
         self
     }
 }
 
-impl<'a> LazyConcat<'a, String, str> {
+impl<'a, const N: usize> LazyConcat<'a, String, str, N> {
     /// Creates an iterator over the `char`s of the String and any concatenated fragments.
     /// No normalization needs to be done for this to work.
     pub fn chars<'b>(&'b self) -> impl Iterator<Item = char> + 'b {
-        self.fragments_iter()
-            .flat_map(|fragment| {
-                fragment.chars()
-            })
+        self.elements()
+    }
+
+    /// The number of `char`s in the String and any concatenated fragments. No normalization needs to be done
+    /// for this to work. 
+    /// 
+    /// The count is cached, so only the data that has been concatenated since the last call needs to be 
+    /// counted. This takes `&mut self` so that the cache can be updated without any synchronization.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut lz = LazyConcat::new(String::from("形聲"))
+    ///     .and_concat("ab");
+    /// assert_eq!(8, lz.len());
+    /// assert_eq!(4, lz.char_len());
+    /// ```
+    pub fn char_len(&mut self) -> usize {
+        let CharCount { len: counted, chars } = self.chars_counted;
+        let mut offset = 0;
+        let mut uncounted = 0;
+        for fragment in self.fragments_iter() {
+            if offset + fragment.len() > counted {
+                // The counted prefix always ends at the end of a fragment, or within the root after a 
+                // split, so this is a char boundary
+                uncounted += fragment[counted.saturating_sub(offset)..].chars().count();
+            }
+            offset += fragment.len();
+        }
+        self.chars_counted = CharCount { len: offset, chars: chars + uncounted };
+        self.chars_counted.chars
     }
 
     /// Creates an iterator over the raw bytes of the String and any concatenated fragments.
@@ -318,16 +965,149 @@ impl<'a> LazyConcat<'a, String, str> {
                 fragment.bytes()
             })
     }
+
+    /// Get a slice from the normalized data, where the range is given in `char`s instead of bytes. Before
+    /// calling this method you should make sure that enough `char`s have been normalized, using
+    /// [`normalize_to_chars`](LazyConcat::normalize_to_chars).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let mut lz = LazyConcat::new(String::new())
+    ///     .and_concat("形聲")
+    ///     .and_concat("網网");
+    /// lz.normalize_to_chars(3);
+    /// assert_eq!("聲網", lz.get_char_slice(1..3));
+    /// ```
+    /// # Panics
+    /// Panics when the range falls outside the normalized `char`s.
+    pub fn get_char_slice<R>(&self, range: R) -> &str
+    where
+        R: RangeBounds<usize>,
+    {
+        match self.try_get_char_slice(range) {
+            Ok(slice) => slice,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Get a slice from the normalized data, where the range is given in `char`s, like 
+    /// [`get_char_slice`](LazyConcat::get_char_slice), but return an error instead of panicking if the
+    /// range is not valid. The positions in the error are also counted in `char`s.
+    pub fn try_get_char_slice<R>(&self, range: R) -> Result<&str, LazyConcatError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = sliceable::try_range(self.chars().count(), range)?;
+        let normalized_len = self.root.chars().count();
+        if end > normalized_len {
+            return Err(LazyConcatError::NotNormalized { end, normalized_len });
+        }
+        let (start, end) = sliceable::char_bounds(&self.root, start .. end)?;
+        Ok(&self.root[start .. end])
+    }
+
+    /// Normalize at least `chars` `char`s and return the number of `char`s that were actually normalized.
+    /// This could fail if there are not enough fragments to make up the required number of `char`s, in
+    /// which case `None` is returned and no work is done.
+    pub fn normalize_to_chars(&mut self, chars: usize) -> Option<usize> {
+        let (count, total) = self.fragments_needed_for_chars(chars).ok()?;
+        if count > 0 {
+            self.normalize_fragments(count);
+        }
+        Some(total)
+    }
+
+    /// Normalize at least `chars` `char`s, like [`normalize_to_chars`](LazyConcat::normalize_to_chars), but 
+    /// return [`LazyConcatError::OutOfBounds`] if there are not enough fragments to make up the required 
+    /// number of `char`s, or [`LazyConcatError::AllocationFailure`] if the memory for the normalized data 
+    /// cannot be allocated. No fragments are normalized in either case.
+    pub fn try_normalize_to_chars(&mut self, chars: usize) -> Result<usize, LazyConcatError> {
+        let (count, total) = self.fragments_needed_for_chars(chars)
+            .map_err(|len| LazyConcatError::OutOfBounds { end: chars, len })?;
+        if count > 0 {
+            self.try_normalize_fragments(count)?;
+        }
+        Ok(total)
+    }
+
+    /// The number of fragments that must be normalized for the root to hold at least `chars` `char`s, and
+    /// the number of `char`s it will then hold. If there are not enough fragments, the error is the total
+    /// number of `char`s.
+    fn fragments_needed_for_chars(&self, chars: usize) -> Result<(usize, usize), usize> {
+        let mut total = self.root.chars().count();
+        if total >= chars {
+            return Ok((0, total));
+        }
+        for (num, fragment) in self.fragments.list.iter().enumerate() {
+            total += fragment.borrow().chars().count();
+            if total >= chars {
+                return Ok((num + 1, total));
+            }
+        }
+        Err(total)
+    }
+
+    /// Fully normalize the collection, like [`normalize`](LazyConcat::normalize), but copy the
+    /// fragments into the root concurrently using scoped threads. The root is grown once, to its final
+    /// size, and each thread copies a disjoint run of fragments into it. Small amounts of data are
+    /// copied on the current thread.
+    ///
+    /// Requires the `parallel` feature.
+    #[cfg(feature = "parallel")]
+    pub fn par_normalize(&mut self) {
+        {
+            let parts: Vec<&[u8]> = self.fragments
+                .iter()
+                .map(str::as_bytes)
+                .collect();
+            // This is safe because every fragment is a valid `str`, so appending their bytes onto
+            // a valid `String` results in valid UTF-8.
+            let root = unsafe { self.root.as_mut_vec() };
+            parallel::par_extend(root, &parts);
+        }
+        self.fragments.clear();
+    }
 }
 
-impl<'a, I: Clone> LazyConcat<'a, Vec<I>, [I]> {
+impl<'a, I: Clone, const N: usize> LazyConcat<'a, Vec<I>, [I], N> {
     /// Creates an iterator over references to items of a Vec and any concatenated fragments.
     /// No normalization needs to be done for this to work.
     pub fn iter(&self) -> impl Iterator<Item = &I> {
-        self.fragments_iter()
-            .flat_map(|slice| {
-                slice.iter()
-            })
+        self.elements()
+    }
+
+    /// Creates an iterator over mutable references to the items of a Vec and any concatenated fragments.
+    /// No normalization needs to be done for this to work, but each borrowed fragment is converted to 
+    /// an owned one when the iterator reaches it.
+    /// 
+    /// # Examples
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let a = [2, 3];
+    /// let b = [4, 5];
+    /// let mut lz = LazyConcat::new(vec![1])
+    ///     .and_concat(&a[..])
+    ///     .and_concat(&b[..]);
+    /// for i in lz.iter_mut().take(2) {
+    ///     *i *= 10;
+    /// }
+    /// // Only the first fragment was touched, so the last is still borrowed
+    /// assert_eq!("LazyConcat { [10], [20, 3], [4, 5] }", format!("{:?}", lz));
+    /// ```
+    pub fn iter_mut<'b>(&'b mut self) -> impl Iterator<Item = &'b mut I> + use<'a, 'b, I, N> {
+        self.chars_counted = CharCount::default();
+        let Fragments { ref mut list, ref mut owned_bytes, .. } = self.fragments;
+        self.root.iter_mut()
+            .chain(list.iter_mut()
+                .flat_map(move |fragment| {
+                    // A borrowed fragment is copied when it is first touched, and then owns its bytes
+                    let before = fragment.owned_byte_len();
+                    let items = fragment.to_mut();
+                    *owned_bytes += items.byte_len() - before;
+                    items.iter_mut()
+                }))
     }
 
     /// Creates an iterator over the owned items of a Vec and any concatenated fragments.
@@ -338,16 +1118,36 @@ impl<'a, I: Clone> LazyConcat<'a, Vec<I>, [I]> {
                 fragment.iter().cloned()
             })
     }
+
+    /// Fully normalize the collection, like [`normalize`](LazyConcat::normalize), but copy the
+    /// fragments into the root concurrently using scoped threads. The root is grown once, to its final
+    /// size, and each thread clones a disjoint run of fragments into it. Small amounts of data are
+    /// copied on the current thread.
+    ///
+    /// Requires the `parallel` feature.
+    #[cfg(feature = "parallel")]
+    pub fn par_normalize(&mut self)
+    where
+        I: Send + Sync,
+    {
+        {
+            let parts: Vec<&[I]> = self.fragments
+                .iter()
+                .collect();
+            parallel::par_extend(&mut self.root, &parts);
+        }
+        self.fragments.clear();
+    }
 }
 
-impl<'a, T, B> Debug for LazyConcat<'a, T, B> 
+impl<'a, T, B, const N: usize> Debug for LazyConcat<'a, T, B, N> 
 where
     T: Concat<Cow<'a, B>> + Borrow<B> + Debug,
     B: ToOwned<Owned = T> + ?Sized + Debug,
 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "LazyConcat {{ {:?}", &self.root)?;
-        for frag in &self.fragments {
+        for frag in self.fragments.iter() {
             write!(f, ", {:?}", &frag)?;
         }
         f.write_str(" }")?;
@@ -355,19 +1155,125 @@ where
     }
 }
 
-impl<'a, T, B> From<T> for LazyConcat<'a, T, B> 
+impl<'a, T, B, const N: usize> From<T> for LazyConcat<'a, T, B, N> 
 where
-    T: Concat<Cow<'a, B>> + Borrow<B> + Default + Length,
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
     B: ToOwned<Owned = T> + ?Sized + Length,
 {
     fn from(base: T) -> Self {
-        LazyConcat::new(base)
+        LazyConcat::new_inline(base)
+    }
+}
+
+/// Borrowed fragments are copied as references and owned data is cloned. Use 
+/// [`fork`](LazyConcat::fork) to share owned data instead.
+impl<'a, T, B, const N: usize> Clone for LazyConcat<'a, T, B, N> 
+where
+    T: Borrow<B>,
+    B: ToOwned<Owned = T> + ?Sized,
+{
+    fn clone(&self) -> Self {
+        LazyConcat { 
+            root: self.root.borrow().to_owned(), 
+            fragments: self.fragments.clone(), 
+            limits: self.limits, 
+            policy: self.policy,
+            chars_counted: self.chars_counted,
+        }
+    }
+}
+
+/// Lazily concatenate every fragment, as with [`concat`](LazyConcat::concat).
+/// 
+/// # Panics
+/// Panics if a limit set with [`limit_len`](LazyConcat::limit_len) or 
+/// [`limit_fragments`](LazyConcat::limit_fragments) would be exceeded.
+impl<'a, T, B, F, const N: usize> Extend<F> for LazyConcat<'a, T, B, N> 
+where
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+    B: ToOwned<Owned = T> + ?Sized + Length,
+    F: Into<Cow<'a, B>>,
+{
+    fn extend<I: IntoIterator<Item = F>>(&mut self, iter: I) {
+        for fragment in iter {
+            self.concat(fragment);
+        }
+    }
+}
+
+/// Collect fragments into a [`LazyConcat`] with an empty root.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use lazy_concat::LazyConcat;
+/// let lz: LazyConcat<String, str> = "a,b,c".split(',').collect();
+/// assert_eq!("abc", lz.done());
+/// ```
+impl<'a, T, B, F, const N: usize> iter::FromIterator<F> for LazyConcat<'a, T, B, N> 
+where
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+    B: ToOwned<Owned = T> + ?Sized + Length,
+    F: Into<Cow<'a, B>>,
+{
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        let mut lz = LazyConcat::new_inline(T::default());
+        lz.extend(iter);
+        lz
+    }
+}
+
+/// Collect fragments into a [`LazyConcat`] with an empty root, as with `collect`.
+impl<'a, T, B, F, const N: usize> iter::Sum<F> for LazyConcat<'a, T, B, N> 
+where
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+    B: ToOwned<Owned = T> + ?Sized + Length,
+    F: Into<Cow<'a, B>>,
+{
+    fn sum<I: Iterator<Item = F>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
+/// Lazily concatenate a fragment, as with [`and_concat`](LazyConcat::and_concat).
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use lazy_concat::LazyConcat;
+/// let mut lz = LazyConcat::new(String::from("a")) + "b";
+/// lz += String::from("c");
+/// assert_eq!("abc", lz.done());
+/// ```
+impl<'a, T, B, F, const N: usize> Add<F> for LazyConcat<'a, T, B, N> 
+where
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+    B: ToOwned<Owned = T> + ?Sized + Length,
+    F: Into<Cow<'a, B>>,
+{
+    type Output = Self;
+
+    fn add(self, fragment: F) -> Self {
+        self.and_concat(fragment)
+    }
+}
+
+/// Lazily concatenate a fragment, as with [`concat`](LazyConcat::concat).
+impl<'a, T, B, F, const N: usize> AddAssign<F> for LazyConcat<'a, T, B, N> 
+where
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+    B: ToOwned<Owned = T> + ?Sized + Length,
+    F: Into<Cow<'a, B>>,
+{
+    fn add_assign(&mut self, fragment: F) {
+        self.concat(fragment);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::LazyConcat;
+    use super::{LazyConcat, LazyConcatError, NormalizePolicy};
+    use std::ops::Bound;
 
     #[test]
     fn test_1() {
@@ -388,6 +1294,91 @@ mod tests {
         assert_eq!("hello there!", res);
     }
 
+    #[test]
+    fn normalize_reserves_once() {
+        let mut lz = LazyConcat::new(String::new())
+            .and_concat("abcdef")
+            .and_concat("ghijkl")
+            .and_concat("m");
+        // Growing the root for each fragment in turn would take its capacity from 8 to 16
+        lz.normalize_to_len(12);
+        assert_eq!(12, lz.root.capacity());
+        // A full normalization reserves exactly, where amortized growth would double the capacity to 24
+        lz.normalize();
+        assert_eq!("abcdefghijklm", lz.root);
+        assert_eq!(13, lz.root.capacity());
+    }
+
+    #[test]
+    fn normalize_adopts_owned_fragment() {
+        let mut owned = String::with_capacity(100);
+        owned.push_str("hello");
+        let ptr = owned.as_ptr();
+        let lz = LazyConcat::new(String::new())
+            .and_concat(owned)
+            .and_concat(" there");
+        let res = lz.done();
+        assert_eq!("hello there", res);
+        assert_eq!(ptr, res.as_ptr());
+    }
+
+    #[test]
+    fn normalize_adopts_owned_fragment_after_root() {
+        let mut owned = Vec::with_capacity(100);
+        owned.extend_from_slice(&[3, 4, 5, 6]);
+        let ptr = owned.as_ptr();
+        let lz = LazyConcat::new(vec![1, 2])
+            .and_concat(owned)
+            .and_concat(&[7][..]);
+        let res = lz.done();
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7], res);
+        assert_eq!(ptr, res.as_ptr());
+    }
+
+    #[test]
+    fn normalize_to_len_adopts_owned_fragment_after_root() {
+        let mut owned = Vec::with_capacity(100);
+        owned.extend_from_slice(&[3, 4, 5, 6]);
+        let ptr = owned.as_ptr();
+        let mut lz = LazyConcat::new(vec![1, 2])
+            .and_concat(owned)
+            .and_concat(&[7][..]);
+        assert_eq!(Some(6), lz.normalize_to_len(4));
+        assert_eq!(ptr, lz.root.as_ptr());
+        let mut lz = LazyConcat::new(String::from("a"))
+            .and_concat(String::with_capacity(100) + "形聲")
+            .and_concat("網");
+        let ptr = lz.fragments.iter().next().unwrap().as_ptr();
+        assert_eq!(Some(3), lz.normalize_to_chars(2));
+        assert_eq!(ptr, lz.root.as_ptr());
+    }
+
+    #[test]
+    fn normalize_copies_os_string_fragment_after_root() {
+        use std::ffi::OsString;
+        let mut owned = OsString::with_capacity(1000);
+        owned.push("b".repeat(500));
+        let lz = LazyConcat::new(OsString::from("a"))
+            .and_concat(owned)
+            .and_concat(OsString::from("c"));
+        let res = lz.done();
+        assert_eq!(502, res.len());
+        // The fragment's buffer isn't adopted, since it would have to be copied to put the root in front
+        assert!(res.capacity() < 1000);
+    }
+
+    #[test]
+    fn normalize_keeps_larger_root() {
+        let mut root = String::with_capacity(100);
+        root.push_str("hello");
+        let ptr = root.as_ptr();
+        let lz = LazyConcat::new(root)
+            .and_concat(String::from(" there"));
+        let res = lz.done();
+        assert_eq!("hello there", res);
+        assert_eq!(ptr, res.as_ptr());
+    }
+
     #[test] 
     fn normalize_to_len() {
         let a = "hel";
@@ -402,6 +1393,165 @@ mod tests {
         assert_eq!("LazyConcat { \"hello the\", \"re!\" }", format!("{:?}", lz));
     }
 
+    #[test]
+    fn fragments_stored_inline() {
+        let mut lz = LazyConcat::<_, _, 2>::new_inline(String::new())
+            .and_concat("a")
+            .and_concat("b");
+        assert!(!lz.fragments.list.spilled());
+        lz.concat("c");
+        assert!(lz.fragments.list.spilled());
+        lz.normalize_to_len(1);
+        assert_eq!("LazyConcat { \"a\", \"b\", \"c\" }", format!("{:?}", lz));
+        assert_eq!("abc", lz.done());
+    }
+
+    #[test]
+    fn concat_coalesces_short_fragments() {
+        let big = vec![10, 11, 12, 13];
+        let lz = LazyConcat::new(Vec::new())
+            .coalesce_below(2)
+            .and_concat(&[1][..])
+            .and_concat(&[2][..])
+            .and_concat(&[][..])
+            .and_concat(&big)
+            .and_concat(&[3][..])
+            .and_concat(vec![4, 5, 6])
+            .and_concat(&[7][..]);
+        assert_eq!("LazyConcat { [], [1, 2], [10, 11, 12, 13], [3], [4, 5, 6, 7] }", format!("{:?}", lz));
+        assert_eq!(vec![1, 2, 10, 11, 12, 13, 3, 4, 5, 6, 7], lz.done());
+    }
+
+    #[test]
+    fn compact() {
+        let mut lz = LazyConcat::new(String::from("x"))
+            .and_concat("a")
+            .and_concat("b")
+            .and_concat("cdefgh")
+            .and_concat("i")
+            .and_concat("j")
+            .coalesce_below(3);
+        assert_eq!("LazyConcat { \"x\", \"a\", \"b\", \"cdefgh\", \"i\", \"j\" }", format!("{:?}", lz));
+        lz.compact();
+        assert_eq!("LazyConcat { \"x\", \"ab\", \"cdefgh\", \"ij\" }", format!("{:?}", lz));
+        assert_eq!("xabcdefghij", lz.done());
+    }
+
+    #[test]
+    fn normalize_to_chars() {
+        let mut lz = LazyConcat::new(String::from("a"))
+            .and_concat("形聲")
+            .and_concat("網")
+            .and_concat("网");
+        assert_eq!(Some(1), lz.normalize_to_chars(1));
+        assert_eq!(Some(3), lz.normalize_to_chars(2));
+        assert_eq!("LazyConcat { \"a形聲\", \"網\", \"网\" }", format!("{:?}", lz));
+        assert_eq!("形聲", lz.get_char_slice(1..3));
+        assert_eq!(None, lz.normalize_to_chars(6));
+        assert_eq!(Some(5), lz.normalize_to_chars(5));
+        assert_eq!("聲網", lz.get_char_slice(2..=3));
+    }
+
+    #[test]
+    fn try_variants() {
+        let mut lz = LazyConcat::new(vec![1, 2])
+            .and_concat(&[3, 4][..])
+            .and_concat(&[5][..]);
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 6, len: 5 }), lz.try_normalize_to_len(6));
+        assert_eq!(Err(LazyConcatError::NotNormalized { end: 3, normalized_len: 2 }), lz.try_get_slice(1..3));
+        assert_eq!(Ok(4), lz.try_normalize_to_len(3));
+        assert_eq!(Ok(&[2, 3][..]), lz.try_get_slice(1..3));
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 7, len: 5 }), lz.try_get_slice(1..7));
+
+        let mut lz = LazyConcat::new(String::from("形"))
+            .and_concat("聲");
+        assert_eq!(Err(LazyConcatError::NotNormalized { end: 2, normalized_len: 1 }), lz.try_get_char_slice(..2));
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 3, len: 2 }), lz.try_get_char_slice(..3));
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 3, len: 2 }), lz.try_normalize_to_chars(3));
+        assert_eq!(Ok(2), lz.try_normalize_to_chars(2));
+        assert_eq!(Ok("聲"), lz.try_get_char_slice(1..));
+    }
+
+    #[test]
+    fn try_get_slice_partially_normalized() {
+        let lz = LazyConcat::new(String::from("abcdef"))
+            .and_concat("ghi");
+        assert_eq!(Err(LazyConcatError::NotNormalized { end: 9, normalized_len: 6 }), lz.try_get_slice(2..));
+        assert_eq!(Err(LazyConcatError::NotNormalized { end: 9, normalized_len: 6 }), lz.try_get_slice(8..));
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 10, len: 9 }), lz.try_get_slice(10..));
+        assert_eq!(Ok("cdef"), lz.try_get_slice(2..6));
+        assert_eq!(Ok(""), lz.try_get_slice(6..6));
+        assert_eq!(Err(LazyConcatError::NotNormalized { end: 9, normalized_len: 6 }), lz.try_get_char_slice(2..));
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 10, len: 9 }), lz.try_get_char_slice(10..));
+        assert_eq!(Ok("ef"), lz.try_get_char_slice(4..6));
+    }
+
+    #[test]
+    fn try_normalize() {
+        let mut lz = LazyConcat::new(vec![1u8])
+            .and_concat(vec![2, 3])
+            .and_concat(&[4][..]);
+        assert_eq!(Ok(3), lz.try_normalize_to_len(2));
+        assert_eq!("LazyConcat { [1, 2, 3], [4] }", format!("{:?}", lz));
+        assert_eq!(Ok(()), lz.try_normalize());
+        assert_eq!("LazyConcat { [1, 2, 3, 4] }", format!("{:?}", lz));
+    }
+
+    #[test]
+    fn try_done() {
+        let mut owned = String::with_capacity(10);
+        owned.push_str("abc");
+        let ptr = owned.as_ptr();
+        let lz = LazyConcat::new(String::new())
+            .and_concat(owned)
+            .and_concat("def");
+        let res = lz.try_done().unwrap();
+        assert_eq!("abcdef", res);
+        assert_eq!(ptr, res.as_ptr());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_char_slice_out_of_bounds() {
+        let lz = LazyConcat::new(String::from("形聲"))
+            .and_concat("網");
+        lz.get_char_slice(1..3);
+    }
+
+    #[test]
+    fn limits() {
+        let mut lz = LazyConcat::new(vec![1, 2])
+            .limit_len(6)
+            .limit_fragments(2);
+        assert_eq!(Ok(()), lz.try_concat(&[3][..]));
+        assert_eq!(Err(LazyConcatError::LengthLimitExceeded { len: 7, max: 6 }), lz.try_concat(vec![4, 5, 6, 7]));
+        assert_eq!(Ok(()), lz.try_concat(vec![4]));
+        assert_eq!(Err(LazyConcatError::FragmentLimitExceeded { max: 2 }), lz.try_concat(&[5][..]));
+        lz.normalize_to_len(3);
+        assert_eq!(Ok(()), lz.try_concat(&[5][..]));
+        assert_eq!("LazyConcat { [1, 2, 3], [4], [5] }", format!("{:?}", lz));
+        assert_eq!(Err(LazyConcatError::LengthLimitExceeded { len: 7, max: 6 }), lz.try_concat(vec![6, 7]));
+    }
+
+    #[test]
+    fn fragment_limit_allows_coalescing() {
+        let mut lz = LazyConcat::new(String::new())
+            .coalesce_below(2)
+            .limit_fragments(1);
+        assert_eq!(Ok(()), lz.try_concat("a"));
+        assert_eq!(Ok(()), lz.try_concat("b"));
+        assert_eq!(Err(LazyConcatError::FragmentLimitExceeded { max: 1 }), lz.try_concat("cd"));
+        assert_eq!("ab", lz.done());
+    }
+
+    #[test]
+    #[should_panic]
+    fn concat_panics_over_limit() {
+        LazyConcat::new(String::new())
+            .limit_len(2)
+            .and_concat("abc");
+    }
+
     #[test] 
     fn string_iter_chars() {
         let a = "hel";
@@ -465,11 +1615,11 @@ mod tests {
 
         {
             assert_eq!(0, lz.get_normalized_len());
-            assert_eq!(true, lz.slice_needs_normalization(1..4));
+            assert!(lz.slice_needs_normalization(1..4));
             assert_eq!("LazyConcat { [], [1, 2, 3], [4, 5], [6, 7, 8] }", format!("{:?}", lz));
 
             lz.normalize_to_len(4);
-            assert_eq!(false, lz.slice_needs_normalization(1..4));
+            assert!(!lz.slice_needs_normalization(1..4));
             assert_eq!("LazyConcat { [1, 2, 3, 4, 5], [6, 7, 8] }", format!("{:?}", lz));
             let slice = lz.get_slice(1..4);
             assert_eq!(vec![2,3,4], slice);
@@ -483,6 +1633,91 @@ mod tests {
         }
     }
 
+    #[test]
+    fn slice_needs_normalization() {
+        let mut lz = LazyConcat::new(vec![1, 2])
+            .and_concat(&[3, 4][..]);
+        assert!(!lz.slice_needs_normalization(..2));
+        assert!(lz.slice_needs_normalization(..=2));
+        assert!(lz.slice_needs_normalization(1..));
+        // Can't be sliced, even after normalizing
+        assert!(!lz.slice_needs_normalization(1..5));
+        lz.normalize();
+        assert!(!lz.slice_needs_normalization(1..));
+
+        let lz = LazyConcat::new(vec![1, 2]);
+        assert!(!lz.slice_needs_normalization(..));
+    }
+
+    #[test]
+    fn ensure_normalized() {
+        let mut lz = LazyConcat::new(vec![1, 2])
+            .and_concat(&[3, 4][..])
+            .and_concat(&[5, 6][..]);
+        assert_eq!(Ok(&[2][..]), lz.ensure_normalized(1..2));
+        assert_eq!(2, lz.get_normalized_len());
+        assert_eq!(Ok(&[2, 3][..]), lz.ensure_normalized(1..=2));
+        assert_eq!(4, lz.get_normalized_len());
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 7, len: 6 }), lz.ensure_normalized(5..7));
+        assert_eq!(Err(LazyConcatError::InvertedRange { start: 6, end: 5 }), lz.ensure_normalized((Bound::Included(6), Bound::Included(4))));
+        assert_eq!(4, lz.get_normalized_len());
+        assert_eq!(Ok(&[4, 5, 6][..]), lz.ensure_normalized(3..));
+    }
+
+    #[test]
+    fn slice() {
+        let mut lz = LazyConcat::new(String::new())
+            .and_concat("Hello")
+            .and_concat(" ")
+            .and_concat("there!");
+        assert_eq!("ell", lz.slice(1..4));
+        assert_eq!("LazyConcat { \"Hello\", \" \", \"there!\" }", format!("{:?}", lz));
+        assert_eq!("Hello there!", lz.as_normalized());
+        assert_eq!("LazyConcat { \"Hello there!\" }", format!("{:?}", lz));
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_out_of_bounds() {
+        let mut lz = LazyConcat::new(vec![1, 2])
+            .and_concat(&[3][..]);
+        lz.slice(1..4);
+    }
+
+    #[test]
+    fn slice_mut() {
+        let a = [3, 4];
+        let mut lz = LazyConcat::new(vec![1, 2])
+            .and_concat(&a[..])
+            .and_concat(vec![5]);
+        for i in lz.slice_mut(1..) {
+            *i *= 10;
+        }
+        assert_eq!(vec![1, 20, 30, 40, 50], lz.done());
+        assert_eq!([3, 4], a);
+    }
+
+    #[test]
+    fn iter_mut_converts_touched_fragments() {
+        let a = [2, 3];
+        let b = [4];
+        let mut lz = LazyConcat::new(vec![1])
+            .and_concat(&a[..])
+            .and_concat(vec![5])
+            .and_concat(&b[..]);
+        {
+            let mut iter = lz.iter_mut().skip(1);
+            *iter.next().unwrap() = 20;
+        }
+        assert!(lz.fragments.list.iter().next().unwrap().is_owned());
+        assert!(!lz.fragments.list.iter().nth(2).unwrap().is_owned());
+        for i in lz.iter_mut() {
+            *i += 1;
+        }
+        assert_eq!(vec![2, 21, 4, 6, 5], lz.done());
+        assert_eq!([2, 3], a);
+    }
+
     #[test]
     fn split_normalized_mut() {
         let a = vec![0,1,2,3,4];
@@ -500,4 +1735,160 @@ mod tests {
         }
         assert_eq!(vec![0,1,2,3,4,99,100], lz.done());
     }
+
+    #[test]
+    fn extend_and_operators() {
+        let a = [3, 4];
+        let mut lz: LazyConcat<Vec<i32>, [i32]> = vec![vec![1], vec![2]].into_iter().collect();
+        lz.extend(vec![&a[..], &a[1..]]);
+        lz += vec![5];
+        let lz = lz + &a[..1];
+        assert_eq!(0, lz.get_normalized_len());
+        assert_eq!(vec![1, 2, 3, 4, 4, 5, 3], lz.done());
+        let lz: LazyConcat<String, str> = ["x", "y"].iter().cloned().sum();
+        assert_eq!("xy", lz.done());
+    }
+
+    #[test]
+    fn append_and_split_off() {
+        let a = [3, 4, 5];
+        let mut lz = LazyConcat::new(vec![1]).and_concat(&a[..]);
+        let other = LazyConcat::new(vec![6, 7]).and_concat(vec![8]);
+        lz.append(other);
+        assert_eq!(3, lz.fragments().num_fragments());
+        let mut tail = lz.split_off(2);
+        assert_eq!(1, lz.fragments().num_fragments());
+        assert_eq!(0, tail.get_normalized_len());
+        let tail_of_tail = tail.split_off(4);
+        assert_eq!(vec![1, 3], lz.done());
+        assert_eq!(vec![4, 5, 6, 7], tail.done());
+        assert_eq!(vec![8], tail_of_tail.done());
+    }
+
+    #[test]
+    fn split_off_in_root() {
+        let mut lz = LazyConcat::new(String::from("hello"))
+            .and_concat(" world");
+        let tail = lz.split_off(4);
+        assert_eq!(0, lz.fragments().num_fragments());
+        assert_eq!("hell", lz.done());
+        assert_eq!("o world", tail.done());
+    }
+
+    #[test]
+    fn try_append_enforces_limits() {
+        let mut lz = LazyConcat::new(String::from("ab"))
+            .limit_fragments(2);
+        let other = LazyConcat::new(String::from("c")).and_concat("d").and_concat("e");
+        assert_eq!(Err(LazyConcatError::FragmentLimitExceeded { max: 2 }), lz.try_append(other));
+        assert_eq!("ab", lz.done());
+    }
+
+    #[test]
+    fn clone_keeps_borrows() {
+        let a = [2, 3];
+        let lz = LazyConcat::new(vec![1])
+            .and_concat(&a[..])
+            .and_concat(vec![4]);
+        let copy = lz.clone();
+        assert!(!copy.fragments.list.iter().next().unwrap().is_owned());
+        assert_eq!(a.as_ptr(), copy.fragments().iter().next().unwrap().as_ptr());
+        assert_eq!(lz.done(), copy.done());
+    }
+
+    #[test]
+    fn fork_diverges() {
+        let mut lz = LazyConcat::new(String::from("ab")).and_concat(String::from("cd"));
+        let mut fork = lz.fork().and_concat("ef");
+        fork.slice_mut(..1).make_ascii_uppercase();
+        lz.concat("gh");
+        assert_eq!("abcdgh", lz.done());
+        assert_eq!("Abcdef", fork.done());
+    }
+
+    #[test]
+    fn elements_across_fragments() {
+        let a = [2, 3];
+        let lz = LazyConcat::new(vec![1]).and_concat(&a[..]).and_concat(vec![]).and_concat(vec![4]);
+        assert_eq!(vec![&1, &2, &3, &4], lz.elements().collect::<Vec<_>>());
+        let lz = LazyConcat::new(String::new()).and_concat("形").and_concat("聲");
+        assert_eq!("形聲", lz.elements().collect::<String>());
+    }
+
+    #[test]
+    fn lengths_in_each_unit() {
+        let mut lz = LazyConcat::new(String::from("a形"))
+            .and_concat("聲b");
+        assert_eq!(8, lz.len());
+        assert_eq!(8, lz.byte_len());
+        assert_eq!(4, lz.char_len());
+        lz.normalize_to_len(4);
+        lz.concat("cd");
+        assert_eq!(6, lz.char_len());
+        lz.root_mut().push('é');
+        assert_eq!(7, lz.char_len());
+        let tail = lz.split_off(1);
+        assert_eq!(1, lz.char_len());
+        assert_eq!("a", lz.done());
+        assert_eq!(6, tail.chars().count());
+    }
+
+    #[test]
+    fn normalize_on_owned_bytes() {
+        let a = [0u16; 8];
+        let mut lz = LazyConcat::new(vec![])
+            .normalize_policy(NormalizePolicy::OwnedBytesAbove(8))
+            .and_concat(&a[..])
+            .and_concat(vec![1u16, 2]);
+        assert_eq!(0, lz.get_normalized_len());
+        lz.concat(vec![3, 4, 5]);
+        assert_eq!(13, lz.get_normalized_len());
+        lz.append(LazyConcat::new(vec![6; 5]));
+        assert_eq!(18, lz.get_normalized_len());
+        let b = [7u16; 4];
+        lz.concat(&b[..]);
+        lz.concat(&b[..]);
+        lz.iter_mut().for_each(|i| *i += 1);
+        assert_eq!(16, lz.fragments.owned_bytes);
+        lz.concat(&b[..]);
+        assert_eq!(30, lz.get_normalized_len());
+    }
+
+    #[test]
+    fn split_normalized_enforces_limits() {
+        let mut lz = LazyConcat::new(String::from("abc"))
+            .limit_len(5);
+        let (root, mut concat_only) = lz.split_normalized();
+        assert_eq!(Ok(()), concat_only.try_concat("de"));
+        assert_eq!(
+            Err(LazyConcatError::LengthLimitExceeded { len: 6, max: 5 }), 
+            concat_only.try_concat("f"));
+        assert_eq!("abc", root);
+        assert_eq!("abcde", lz.done());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn par_normalize_vec() {
+        let a: Vec<u64> = (0..70_000).collect();
+        let b: Vec<u64> = (70_000..140_000).collect();
+        let mut lz = LazyConcat::new(vec![])
+            .and_concat(&a)
+            .and_concat(b);
+        lz.par_normalize();
+        assert_eq!(140_000, lz.get_normalized_len());
+        assert_eq!((0..140_000).collect::<Vec<u64>>(), lz.done());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn par_normalize_string() {
+        let a = "形聲".repeat(20_000);
+        let b = "abc".repeat(20_000);
+        let mut lz = LazyConcat::new(String::from("网"))
+            .and_concat(&a[..])
+            .and_concat(b.clone());
+        lz.par_normalize();
+        assert_eq!(format!("网{}{}", a, b), lz.done());
+    }
 }
@@ -0,0 +1,116 @@
+use std::borrow::{Borrow, Cow};
+use ::concat::{Concat, Prepend};
+use ::fragments::Fragment;
+use ::length::Length;
+use ::reserve::Reserve;
+use ::LazyConcat;
+
+/// A breakdown of the memory used by a [`LazyConcat`](::LazyConcat), returned by 
+/// [`memory_stats`](::LazyConcat::memory_stats).
+/// 
+/// Sizes of data are in bytes, as measured by [`Length::byte_len`](::Length::byte_len). Capacities of 
+/// buffers are in bytes too, as measured by [`Reserve::byte_capacity`](::Reserve::byte_capacity), while the
+/// capacity of the fragment list is in fragments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryStats {
+    /// The size of the normalized data in the root.
+    pub normalized_bytes: usize,
+    /// The total size of fragments that own their data.
+    pub owned_fragment_bytes: usize,
+    /// The total size of fragments that are shared with forks, made by [`fork`](::LazyConcat::fork).
+    pub shared_fragment_bytes: usize,
+    /// The total size of fragments that borrow their data, which this `LazyConcat` doesn't pay for.
+    pub borrowed_fragment_bytes: usize,
+    /// The number of fragments that are not yet normalized.
+    pub num_fragments: usize,
+    /// The number of fragments that can be held without reallocating the list of fragments.
+    pub fragments_capacity: usize,
+    /// The capacity of the root in bytes, which is allocated but not necessarily used.
+    pub root_byte_capacity: usize,
+    /// The total capacity in bytes of the buffers of owned and shared fragments, which can be more than 
+    /// the size of their data.
+    pub fragment_byte_capacity: usize,
+}
+
+impl MemoryStats {
+    /// The total size of the data that is owned, including fragments that are shared with forks.
+    pub fn owned_bytes(&self) -> usize {
+        self.normalized_bytes + self.owned_fragment_bytes + self.shared_fragment_bytes
+    }
+}
+
+impl<'a, T, B, const N: usize> LazyConcat<'a, T, B, N> 
+where
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+    B: ToOwned<Owned = T> + ?Sized + Length,
+{
+    /// Report how much memory is used by the normalized root and the fragments. This is calculated from 
+    /// the lengths of the data, without normalizing.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use lazy_concat::LazyConcat;
+    /// let lz = LazyConcat::new(String::from("abc"))
+    ///     .and_concat("borrowed")
+    ///     .and_concat(String::from("owned"));
+    /// let stats = lz.memory_stats();
+    /// assert_eq!(3, stats.normalized_bytes);
+    /// assert_eq!(5, stats.owned_fragment_bytes);
+    /// assert_eq!(8, stats.borrowed_fragment_bytes);
+    /// assert_eq!(2, stats.num_fragments);
+    /// ```
+    pub fn memory_stats(&self) -> MemoryStats {
+        let mut stats = MemoryStats {
+            normalized_bytes: self.root.byte_len(),
+            num_fragments: self.fragments.num_fragments(),
+            fragments_capacity: self.fragments.list.capacity(),
+            root_byte_capacity: self.root.byte_capacity(),
+            ..MemoryStats::default()
+        };
+        for fragment in self.fragments.list.iter() {
+            let bytes = fragment.borrow().byte_len();
+            match fragment {
+                Fragment::Value(Cow::Owned(owned)) => {
+                    stats.owned_fragment_bytes += bytes;
+                    stats.fragment_byte_capacity += owned.byte_capacity();
+                }
+                Fragment::Value(Cow::Borrowed(_)) => stats.borrowed_fragment_bytes += bytes,
+                Fragment::Shared(shared) => {
+                    stats.shared_fragment_bytes += bytes;
+                    stats.fragment_byte_capacity += shared.byte_capacity();
+                }
+            }
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::{LazyConcat, MemoryStats};
+
+    #[test]
+    fn vec_stats() {
+        let a = [3u32, 4];
+        let mut lz = LazyConcat::new(Vec::with_capacity(10))
+            .and_concat(vec![1u32, 2])
+            .and_concat(&a[..]);
+        lz.root_mut().push(0);
+        let mut fork = lz.fork();
+        fork.concat(vec![5]);
+        assert_eq!(MemoryStats {
+            normalized_bytes: 0,
+            owned_fragment_bytes: 4,
+            shared_fragment_bytes: 12,
+            borrowed_fragment_bytes: 8,
+            num_fragments: 4,
+            fragments_capacity: 4,
+            root_byte_capacity: 0,
+            fragment_byte_capacity: 52,
+        }, fork.memory_stats());
+        lz.normalize();
+        assert_eq!(lz.memory_stats().root_byte_capacity, 4 * lz.root.capacity());
+        assert_eq!(16, fork.memory_stats().owned_bytes());
+    }
+}
@@ -0,0 +1,82 @@
+//! Parallel copying of fragments into a root buffer, enabled with the `parallel` cargo feature.
+
+use std::mem::MaybeUninit;
+use std::thread;
+
+/// Below this many elements, the cost of spawning threads outweighs the benefit, so the copy is
+/// done on the current thread.
+const MIN_PARALLEL_LEN: usize = 64 * 1024;
+
+/// Append clones of all of the `parts` onto the end of `dst`, copying disjoint regions of the
+/// destination concurrently on scoped threads.
+pub(crate) fn par_extend<T>(dst: &mut Vec<T>, parts: &[&[T]])
+where
+    T: Clone + Send + Sync,
+{
+    let total: usize = parts.iter().map(|part| part.len()).sum();
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    if total < MIN_PARALLEL_LEN || threads < 2 {
+        dst.reserve_exact(total);
+        for part in parts {
+            dst.extend_from_slice(part);
+        }
+        return;
+    }
+
+    dst.reserve_exact(total);
+    let chunk_len = total.div_ceil(threads);
+    {
+        let mut spare = &mut dst.spare_capacity_mut()[..total];
+        thread::scope(|scope| {
+            let mut parts = parts;
+            while !parts.is_empty() {
+                // Give each thread a run of whole fragments amounting to at least `chunk_len` elements
+                let mut len = 0;
+                let mut count = 0;
+                while count < parts.len() && len < chunk_len {
+                    len += parts[count].len();
+                    count += 1;
+                }
+                let (group, rest_parts) = parts.split_at(count);
+                let (region, rest_spare) = spare.split_at_mut(len);
+                parts = rest_parts;
+                spare = rest_spare;
+                scope.spawn(move || copy_into(region, group));
+            }
+        });
+    }
+    // This is safe because every one of the `total` spare elements was initialized by exactly one thread
+    // above, and `thread::scope` propagates any panic before this point is reached.
+    unsafe {
+        dst.set_len(dst.len() + total);
+    }
+}
+
+fn copy_into<T: Clone>(region: &mut [MaybeUninit<T>], parts: &[&[T]]) {
+    let items = parts.iter().flat_map(|part| part.iter());
+    for (slot, item) in region.iter_mut().zip(items) {
+        slot.write(item.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn par_extend_small() {
+        let mut v = vec![1, 2];
+        par_extend(&mut v, &[&[3, 4], &[], &[5]]);
+        assert_eq!(vec![1, 2, 3, 4, 5], v);
+    }
+
+    #[test]
+    fn par_extend_large() {
+        let a: Vec<u32> = (0..100_000).collect();
+        let b: Vec<u32> = (100_000..100_003).collect();
+        let c: Vec<u32> = (100_003..250_000).collect();
+        let mut v = Vec::new();
+        par_extend(&mut v, &[&a, &b, &c]);
+        assert_eq!((0..250_000).collect::<Vec<u32>>(), v);
+    }
+}
@@ -0,0 +1,32 @@
+use ::fragments::Fragments;
+use ::length::Length;
+
+/// When a [`LazyConcat`](::LazyConcat) should normalize automatically, set with 
+/// [`normalize_policy`](::LazyConcat::normalize_policy).
+/// 
+/// The policy is applied each time a fragment is concatenated, after the fragment has been added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalizePolicy {
+    /// Only normalize when asked to. This is the default.
+    #[default]
+    Never,
+    /// Normalize fully when there are more than this many fragments.
+    FragmentsAbove(usize),
+    /// Normalize fully when the owned fragments, including those shared with forks, hold more than this 
+    /// many bytes, as measured by [`Length::byte_len`](::Length::byte_len). Borrowed fragments are not 
+    /// counted, since they don't use any memory of their own.
+    OwnedBytesAbove(usize),
+}
+
+impl NormalizePolicy {
+    pub(crate) fn should_normalize<'a, B, const N: usize>(&self, fragments: &Fragments<'a, B, N>) -> bool
+    where
+        B: ToOwned + ?Sized + Length,
+    {
+        match *self {
+            NormalizePolicy::Never => false,
+            NormalizePolicy::FragmentsAbove(max) => fragments.num_fragments() > max,
+            NormalizePolicy::OwnedBytesAbove(max) => fragments.owned_bytes > max,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::TryReserveError;
+use std::ffi::OsString;
+use std::mem;
+
+/// Reserving capacity in an owned value, ahead of concatenating more data onto it.
+///
+/// [`LazyConcat`](::LazyConcat) knows the length of every fragment before it normalizes, so it uses
+/// this to grow the root just once, rather than each time a fragment is concatenated.
+/// 
+/// Capacity is measured in the units of [`Length`](::Length). Reserving is only an optimization, so an 
+/// implementation that does nothing is correct, if slower.
+pub trait Reserve {
+    /// Reserve capacity for at least `additional` more units of length, as measured by
+    /// [`Length`](::Length).
+    fn reserve(&mut self, additional: usize);
+
+    /// Try to reserve capacity for at least `additional` more units of length, returning an error instead
+    /// of aborting if the memory cannot be allocated.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError>;
+
+    /// Reserve capacity for exactly `additional` more units of length, without over-allocating to leave 
+    /// room for growth. This is used when every fragment is normalized at once, since nothing else is 
+    /// expected to be concatenated onto the root. The default implementation calls 
+    /// [`reserve`](Reserve::reserve).
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    /// Try to reserve capacity for exactly `additional` more units of length, like 
+    /// [`reserve_exact`](Reserve::reserve_exact), but return an error instead of aborting if the memory 
+    /// cannot be allocated. The default implementation calls [`try_reserve`](Reserve::try_reserve).
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_reserve(additional)
+    }
+
+    /// The number of units of length that can be held without reallocating.
+    fn capacity(&self) -> usize;
+
+    /// The size in bytes of the allocated buffer, which is not necessarily all used. The default 
+    /// implementation assumes that each unit of length is one byte, and returns 
+    /// [`capacity`](Reserve::capacity).
+    fn byte_capacity(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T> Reserve for Vec<T> {
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_reserve(additional)
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_reserve_exact(additional)
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    fn byte_capacity(&self) -> usize {
+        self.capacity() * mem::size_of::<T>()
+    }
+}
+
+impl Reserve for String {
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_reserve(additional)
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_reserve_exact(additional)
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl Reserve for OsString {
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_reserve(additional)
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_reserve_exact(additional)
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+}
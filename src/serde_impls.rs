@@ -0,0 +1,145 @@
+//! Serialization and deserialization with serde, enabled with the `serde` cargo feature.
+//!
+//! A `LazyConcat<String, str>` is serialized as a string and a `LazyConcat<Vec<T>, [T]>` as a sequence,
+//! streaming the fragments without normalizing them. Deserializing produces a normalized `LazyConcat`.
+//! To keep the boundaries between fragments, use the [`serde_fragments`](::serde_fragments) module 
+//! instead.
+
+use std::borrow::{Borrow, Cow};
+use std::fmt::{self, Display, Formatter};
+use std::iter;
+use std::marker::PhantomData;
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+use ::concat::{Concat, Prepend};
+use ::length::Length;
+use ::reserve::Reserve;
+use ::LazyConcat;
+
+/// Writes each fragment in turn, so that a serializer which supports it can stream the string.
+struct Chunks<'b, 'a: 'b, const N: usize>(&'b LazyConcat<'a, String, str, N>);
+
+impl<'b, 'a: 'b, const N: usize> Display for Chunks<'b, 'a, N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fragments_iter().try_for_each(|fragment| f.write_str(fragment))
+    }
+}
+
+impl<'a, const N: usize> Serialize for LazyConcat<'a, String, str, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Chunks(self))
+    }
+}
+
+impl<'a, I, const N: usize> Serialize for LazyConcat<'a, Vec<I>, [I], N>
+where
+    I: Clone + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, 'a, const N: usize> Deserialize<'de> for LazyConcat<'a, String, str, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(LazyConcat::new_inline)
+    }
+}
+
+impl<'de, 'a, I, const N: usize> Deserialize<'de> for LazyConcat<'a, Vec<I>, [I], N>
+where
+    I: Clone + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(LazyConcat::new_inline)
+    }
+}
+
+/// Serialization that preserves the boundaries between fragments, for use with 
+/// `#[serde(with = "lazy_concat::serde_fragments")]`.
+///
+/// The `LazyConcat` is serialized as a sequence, starting with the normalized root and followed by each 
+/// fragment. When deserialized, the root is restored and every fragment becomes an owned fragment.
+///
+/// Requires the `serde` feature.
+pub mod serde_fragments {
+    use super::*;
+
+    /// Serialize the root and each fragment as a sequence.
+    pub fn serialize<'a, T, B, S, const N: usize>(lz: &LazyConcat<'a, T, B, N>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+        B: ToOwned<Owned = T> + ?Sized + Length + Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(iter::once(lz.root.borrow()).chain(lz.fragments.iter()))
+    }
+
+    /// Deserialize a sequence into a root followed by owned fragments.
+    pub fn deserialize<'de, 'a, T, B, D, const N: usize>(deserializer: D) -> Result<LazyConcat<'a, T, B, N>, D::Error>
+    where
+        T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve + Deserialize<'de>,
+        B: ToOwned<Owned = T> + ?Sized + Length,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(FragmentsVisitor(PhantomData))
+    }
+
+    struct FragmentsVisitor<'a, T, B: ?Sized + 'a, const N: usize>(PhantomData<fn() -> LazyConcat<'a, T, B, N>>)
+    where
+        B: ToOwned;
+
+    impl<'de, 'a, T, B, const N: usize> Visitor<'de> for FragmentsVisitor<'a, T, B, N>
+    where
+        T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve + Deserialize<'de>,
+        B: ToOwned<Owned = T> + ?Sized + Length,
+    {
+        type Value = LazyConcat<'a, T, B, N>;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a sequence of fragments")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut lz = LazyConcat::new_inline(seq.next_element()?.unwrap_or_default());
+            while let Some(fragment) = seq.next_element::<T>()? {
+                lz.concat(Cow::Owned(fragment));
+            }
+            Ok(lz)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::{serde_fragments, LazyConcat};
+    use serde_json;
+
+    #[test]
+    fn string_as_str() {
+        let lz = LazyConcat::new(String::from("ab")).and_concat("cd");
+        assert_eq!("\"abcd\"", serde_json::to_string(&lz).unwrap());
+        let lz: LazyConcat<String, str> = serde_json::from_str("\"abcd\"").unwrap();
+        assert_eq!(4, lz.get_normalized_len());
+        assert_eq!("abcd", lz);
+    }
+
+    #[test]
+    fn vec_as_seq() {
+        let a = [2, 3];
+        let lz = LazyConcat::new(vec![1u8]).and_concat(&a[..]);
+        assert_eq!("[1,2,3]", serde_json::to_string(&lz).unwrap());
+        let lz: LazyConcat<Vec<u8>, [u8]> = serde_json::from_str("[1,2,3]").unwrap();
+        assert_eq!(vec![1, 2, 3], lz);
+    }
+
+    #[test]
+    fn fragments_preserved() {
+        let lz = LazyConcat::new(String::from("ab")).and_concat("c").and_concat("de");
+        let mut json = Vec::new();
+        serde_fragments::serialize(&lz, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(b"[\"ab\",\"c\",\"de\"]", &json[..]);
+        let lz: LazyConcat<String, str> = serde_fragments::deserialize(&mut serde_json::Deserializer::from_slice(&json)).unwrap();
+        assert_eq!("LazyConcat { \"ab\", \"c\", \"de\" }", format!("{:?}", lz));
+    }
+}
@@ -1,39 +1,110 @@
-/// A trait for types that can have slices taken from them.
-
 use ::length::Length;
+use ::error::LazyConcatError;
 use std::ops::{Bound, RangeBounds};
 
+/// A trait for types that can have slices taken from them.
+/// 
+/// Ranges are measured in the units of [`Length`]. The full range, `get_slice(..)`, must be the same data
+/// as `Borrow::borrow`, and a slice of the range `a..b` must have a length of `b - a`.
+/// 
+/// No unsafe code in this crate relies on these traits, so an incorrect implementation can cause panics or
+/// wrong results, but not undefined behaviour.
 pub trait Sliceable: Length {
     type Slice: ?Sized;
 
+    /// Get a slice of the given range.
+    /// 
+    /// # Panics
+    /// Panics if the range is out of bounds, or is not valid for this value.
     fn get_slice<R>(&self, range: R) -> &Self::Slice
     where 
         R: RangeBounds<usize>;
 
+    /// Get a slice, returning an error instead of panicking if the range is not valid for this value.
+    fn try_get_slice<R>(&self, range: R) -> Result<&Self::Slice, LazyConcatError>
+    where 
+        R: RangeBounds<usize>
+    {
+        let (start, end) = try_bounds(self, range)?;
+        Ok(self.get_slice(start .. end))
+    }
+
+    #[deprecated(note = "Use `get_slice(..)` to borrow the whole value instead")]
     fn as_ptr(&self) -> *const Self::Slice {
         self.get_slice(..)
     }
 }
 
+/// A trait for types that can have mutable slices taken from them.
+pub trait SliceableMut: Sliceable {
+    fn get_slice_mut<R>(&mut self, range: R) -> &mut Self::Slice
+    where 
+        R: RangeBounds<usize>;
+}
+
 fn bounds<T, R>(target: &T, range: R) -> (usize, usize)
 where
-    T: Length,
+    T: Length + ?Sized,
     R: RangeBounds<usize>,
 {
-    let len = target.len();
+    match try_bounds(target, range) {
+        Ok(bounds) => bounds,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+pub(crate) fn try_bounds<T, R>(target: &T, range: R) -> Result<(usize, usize), LazyConcatError>
+where
+    T: Length + ?Sized,
+    R: RangeBounds<usize>,
+{
+    try_range(target.len(), range)
+}
+
+/// Resolve a range into start and end positions, checking that it is valid for data of length `len`.
+pub(crate) fn try_range<R>(len: usize, range: R) -> Result<(usize, usize), LazyConcatError>
+where
+    R: RangeBounds<usize>,
+{
+    // Saturate rather than overflow, since `usize::MAX + 1` is out of bounds anyway
     let start = match range.start_bound() {
         Bound::Unbounded => 0,
         Bound::Included(n) => *n,
-        Bound::Excluded(n) => *n + 1,
+        Bound::Excluded(n) => n.saturating_add(1),
     };
     let end = match range.end_bound() {
         Bound::Unbounded => len,
-        Bound::Included(n) => *n + 1,
+        Bound::Included(n) => n.saturating_add(1),
         Bound::Excluded(n) => *n,
     };
-    assert!(start <= end);
-    assert!(end <= len);
-    (start, end)
+    if end > len {
+        Err(LazyConcatError::OutOfBounds { end, len })
+    } else if start > len {
+        Err(LazyConcatError::OutOfBounds { end: start, len })
+    } else if start > end {
+        Err(LazyConcatError::InvertedRange { start, end })
+    } else {
+        Ok((start, end))
+    }
+}
+
+/// Convert a range of `char` indices into a range of byte offsets in `s`.
+pub(crate) fn char_bounds<R>(s: &str, range: R) -> Result<(usize, usize), LazyConcatError>
+where
+    R: RangeBounds<usize>,
+{
+    let len = s.chars().count();
+    let (start, end) = try_range(len, range)?;
+    let mut offsets = s.char_indices()
+        .map(|(offset, _)| offset)
+        .chain(Some(s.len()));
+    let start_offset = offsets.nth(start).unwrap_or(s.len());
+    let end_offset = if end == start {
+        start_offset
+    } else {
+        offsets.nth(end - start - 1).unwrap_or(s.len())
+    };
+    Ok((start_offset, end_offset))
 }
 
 impl<T> Sliceable for Vec<T> {
@@ -47,6 +118,16 @@ impl<T> Sliceable for Vec<T> {
     }
 }
 
+impl<T> SliceableMut for Vec<T> {
+    fn get_slice_mut<R>(&mut self, range: R) -> &mut Self::Slice 
+    where
+        R: RangeBounds<usize>
+    {
+        let (start, end) = bounds(self, range);
+        &mut self[start .. end]
+    }
+}
+
 impl Sliceable for String {
     type Slice = str;
     fn get_slice<R>(&self, range: R) -> &Self::Slice 
@@ -56,6 +137,57 @@ impl Sliceable for String {
         let (start, end) = bounds(self, range);
         &self[start .. end]
     }
+
+    fn try_get_slice<R>(&self, range: R) -> Result<&Self::Slice, LazyConcatError>
+    where 
+        R: RangeBounds<usize>
+    {
+        self.as_str().try_get_slice(range)
+    }
+}
+
+impl SliceableMut for String {
+    fn get_slice_mut<R>(&mut self, range: R) -> &mut Self::Slice 
+    where
+        R: RangeBounds<usize>
+    {
+        let (start, end) = bounds(self, range);
+        &mut self[start .. end]
+    }
+}
+
+impl<T> Sliceable for [T] {
+    type Slice = [T];
+    fn get_slice<R>(&self, range: R) -> &Self::Slice 
+    where
+        R: RangeBounds<usize>
+    {
+        let (start, end) = bounds(self, range);
+        &self[start .. end]
+    }
+}
+
+impl Sliceable for str {
+    type Slice = str;
+    fn get_slice<R>(&self, range: R) -> &Self::Slice 
+    where
+        R: RangeBounds<usize>
+    {
+        let (start, end) = bounds(self, range);
+        &self[start .. end]
+    }
+
+    fn try_get_slice<R>(&self, range: R) -> Result<&Self::Slice, LazyConcatError>
+    where 
+        R: RangeBounds<usize>
+    {
+        let (start, end) = try_bounds(self, range)?;
+        if let Some(&index) = [start, end].iter().find(|&&i| !self.is_char_boundary(i)) {
+            Err(LazyConcatError::NotCharBoundary { index })
+        } else {
+            Ok(&self[start .. end])
+        }
+    }
 }
 
 #[cfg(test)]
@@ -82,4 +214,62 @@ mod test {
         let slice: &str = string.get_slice(0 ..= 2);
         assert_eq!("hel", slice);
     }
+
+    #[test]
+    fn test_unsized() {
+        let slice: &[u32] = &[0,1,2,3];
+        assert_eq!(&[1, 2], slice.get_slice(1..3));
+        assert_eq!("ll", "hello".get_slice(2..4));
+    }
+
+    #[test]
+    fn test_unsized_try_get_slice() {
+        assert_eq!(Err(LazyConcatError::NotCharBoundary { index: 1 }), "形聲".try_get_slice(1..3));
+        assert_eq!(Ok("形"), "形聲".try_get_slice(0..3));
+        let slice: &[u32] = &[0, 1];
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 3, len: 2 }), slice.try_get_slice(1..3));
+    }
+
+    #[test]
+    fn test_vec_mut() {
+        let mut vec = vec![0,1,2,3,4,5];
+        vec.get_slice_mut(1..3).copy_from_slice(&[10, 20]);
+        assert_eq!(vec![0,10,20,3,4,5], vec);
+    }
+
+    #[test]
+    fn test_string_mut() {
+        let mut string = String::from("hello");
+        string.get_slice_mut(..2).make_ascii_uppercase();
+        assert_eq!("HEllo", string);
+    }
+
+    #[test]
+    fn test_try_vec() {
+        let vec = vec![0,1,2,3,4,5];
+        assert_eq!(Ok(&[1, 2][..]), vec.try_get_slice(1..3));
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 7, len: 6 }), vec.try_get_slice(1..7));
+        assert_eq!(Err(LazyConcatError::InvertedRange { start: 3, end: 1 }), vec.try_get_slice((Bound::Included(3), Bound::Excluded(1))));
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: usize::MAX, len: 6 }), vec.try_get_slice(..=usize::MAX));
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 10, len: 6 }), vec.try_get_slice(10..));
+        assert_eq!(Ok(&[][..]), vec.try_get_slice(6..));
+    }
+
+    #[test]
+    fn test_try_string_char_boundary() {
+        let string = String::from("a形聲");
+        assert_eq!(Ok("a形"), string.try_get_slice(..4));
+        assert_eq!(Err(LazyConcatError::NotCharBoundary { index: 2 }), string.try_get_slice(..2));
+        assert_eq!(Err(LazyConcatError::NotCharBoundary { index: 5 }), string.try_get_slice(5..));
+    }
+
+    #[test]
+    fn test_char_bounds() {
+        let s = "a形聲b";
+        assert_eq!(Ok((1, 7)), char_bounds(s, 1..3));
+        assert_eq!(Ok((7, 8)), char_bounds(s, 3..));
+        assert_eq!(Ok((8, 8)), char_bounds(s, 4..));
+        assert_eq!(Ok((4, 4)), char_bounds(s, 2..2));
+        assert_eq!(Err(LazyConcatError::OutOfBounds { end: 5, len: 4 }), char_bounds(s, ..5));
+    }
 }
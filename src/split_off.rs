@@ -0,0 +1,24 @@
+/// Splitting an owned value in two at a position, as measured by [`Length`](::Length).
+///
+/// [`LazyConcat::split_off`](::LazyConcat::split_off) uses this to divide the root or an owned fragment,
+/// without copying the data before the split.
+pub trait SplitOff {
+    /// Split the value at `at`, returning everything from `at` onwards and leaving everything before it
+    /// in place.
+    ///
+    /// # Panics
+    /// Panics if `at` is out of bounds, or if it is not a valid position at which to split the value.
+    fn split_off(&mut self, at: usize) -> Self;
+}
+
+impl<T> SplitOff for Vec<T> {
+    fn split_off(&mut self, at: usize) -> Self {
+        self.split_off(at)
+    }
+}
+
+impl SplitOff for String {
+    fn split_off(&mut self, at: usize) -> Self {
+        self.split_off(at)
+    }
+}
@@ -0,0 +1,94 @@
+use std::borrow::{Borrow, Cow};
+use std::ffi::{OsStr, OsString};
+use ::concat::{Concat, Prepend};
+use ::length::Length;
+use ::reserve::Reserve;
+use ::LazyConcat;
+
+/// Conversion of an owned value into a [`LazyConcat`](::LazyConcat), so that further fragments can be 
+/// concatenated onto it with method chaining.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use lazy_concat::ToLazyConcat;
+/// let lz = String::from("Hello")
+///     .lazy()
+///     .and_concat(", ")
+///     .and_concat("world");
+/// assert_eq!("Hello, world", lz.done());
+/// ```
+pub trait ToLazyConcat<'a, B>
+where
+    B: ToOwned + ?Sized + 'a,
+{
+    /// Create a [`LazyConcat`](::LazyConcat) with `self` as its normalized root.
+    fn to_lazy_concat(self) -> LazyConcat<'a, B::Owned, B>;
+
+    /// A shorter name for [`to_lazy_concat`](ToLazyConcat::to_lazy_concat).
+    fn lazy(self) -> LazyConcat<'a, B::Owned, B>
+    where
+        Self: Sized,
+    {
+        self.to_lazy_concat()
+    }
+}
+
+impl<'a> ToLazyConcat<'a, str> for String {
+    fn to_lazy_concat(self) -> LazyConcat<'a, String, str> {
+        LazyConcat::new(self)
+    }
+}
+
+impl<'a, T: Clone + 'a> ToLazyConcat<'a, [T]> for Vec<T> {
+    fn to_lazy_concat(self) -> LazyConcat<'a, Vec<T>, [T]> {
+        LazyConcat::new(self)
+    }
+}
+
+impl<'a> ToLazyConcat<'a, OsStr> for OsString {
+    fn to_lazy_concat(self) -> LazyConcat<'a, OsString, OsStr> {
+        LazyConcat::new(self)
+    }
+}
+
+/// A borrowed `Cow` is not copied. It becomes the first fragment, in front of an empty root.
+impl<'a, T, B> ToLazyConcat<'a, B> for Cow<'a, B>
+where
+    T: Concat<Cow<'a, B>> + Prepend + Borrow<B> + Default + Length + Reserve,
+    B: ToOwned<Owned = T> + ?Sized + Length,
+{
+    fn to_lazy_concat(self) -> LazyConcat<'a, T, B> {
+        match self {
+            Cow::Owned(owned) => LazyConcat::new(owned),
+            Cow::Borrowed(_) => LazyConcat::new_empty().and_concat(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToLazyConcat;
+    use std::borrow::Cow;
+    use std::ffi::{OsStr, OsString};
+
+    #[test]
+    fn vec_lazy() {
+        let lz = vec![1, 2].lazy().and_concat(&[3][..]);
+        assert_eq!(vec![1, 2, 3], lz.done());
+    }
+
+    #[test]
+    fn os_string_lazy() {
+        let lz = OsString::from("/usr").lazy().and_concat(OsStr::new("/bin"));
+        assert_eq!(4, lz.get_normalized_len());
+        assert_eq!(OsString::from("/usr/bin"), lz.done());
+    }
+
+    #[test]
+    fn borrowed_cow_is_a_fragment() {
+        let lz = Cow::Borrowed("abc").lazy().and_concat("def");
+        assert_eq!(0, lz.get_normalized_len());
+        assert_eq!("abcdef", lz.done());
+    }
+}